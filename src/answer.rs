@@ -0,0 +1,147 @@
+use num_bigint::BigInt;
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+use std::convert::{Infallible, TryFrom};
+use std::fmt;
+use std::str::FromStr;
+
+/// What a part produces. Every way of building one normalises it, so answers compare equal exactly when they print the
+/// same: an integer is an `Int` whatever type the solver used, only integers too big for an `i128` are `Big`, text that
+/// spells out an integer is that integer, and text spanning several lines is `Lines`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Int(i128),
+    Big(BigInt),
+    Text(String),
+    /// Text spread over several lines, such as a rendered grid
+    Lines(Vec<String>),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+            Answer::Big(n) => write!(f, "{}", n),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Lines(lines) => write!(f, "{}", lines.join("\n")),
+        }
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($t:ty),*) => {
+        $(
+          impl From<$t> for Answer {
+              fn from(n: $t) -> Answer {
+                  Answer::Int(n as i128)
+              }
+          }
+        )*
+    };
+}
+
+impl_from_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, i128, isize);
+
+impl From<u128> for Answer {
+    fn from(n: u128) -> Answer {
+        match i128::try_from(n) {
+            Ok(n) => Answer::Int(n),
+            Err(_) => Answer::Big(BigInt::from(n)),
+        }
+    }
+}
+
+impl From<BigInt> for Answer {
+    fn from(n: BigInt) -> Answer {
+        match i128::try_from(&n) {
+            Ok(n) => Answer::Int(n),
+            Err(_) => Answer::Big(n),
+        }
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Answer {
+        if text.contains('\n') {
+            return Answer::Lines(text.split('\n').map(str::to_owned).collect());
+        }
+
+        // Only integers written the way they'd print, so "007" or "+1" stay as they are
+        match text.parse::<BigInt>() {
+            Ok(n) if n.to_string() == text => Answer::from(n),
+            _ => Answer::Text(text.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Answer {
+        Answer::from(text.as_str())
+    }
+}
+
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Answer::from(s))
+    }
+}
+
+/// Answers are stored as the text they print as, which is also what gets submitted
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Answer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalises() {
+        assert_eq!(Answer::from(5u16), Answer::from(5i32));
+        assert_eq!(Answer::from(5usize), Answer::from("5"));
+        assert_eq!(Answer::from(-3i64), Answer::from("-3".to_owned()));
+        assert_eq!(Answer::from(BigInt::from(42)), Answer::Int(42));
+
+        assert_eq!(
+            Answer::from(u128::MAX),
+            Answer::Big(BigInt::from(u128::MAX))
+        );
+        assert_eq!(Answer::from(u128::MAX.to_string()), Answer::from(u128::MAX));
+
+        assert_eq!(Answer::from("007"), Answer::Text("007".to_owned()));
+        assert_eq!(Answer::from("abc"), Answer::Text("abc".to_owned()));
+        assert_eq!(
+            Answer::from("#.\n.#"),
+            Answer::Lines(vec!["#.".to_owned(), ".#".to_owned()])
+        );
+    }
+
+    #[test]
+    fn round_trips() {
+        let answers = vec![
+            Answer::Int(-12),
+            Answer::from(u128::MAX),
+            Answer::from("abc"),
+            Answer::from("#.\n.#"),
+        ];
+
+        for answer in answers {
+            assert_eq!(Answer::from(answer.to_string()), answer);
+
+            let json = serde_json::to_string(&answer).unwrap();
+            assert_eq!(serde_json::from_str::<Answer>(&json).unwrap(), answer);
+        }
+
+        assert_eq!(serde_json::to_string(&Answer::Int(7)).unwrap(), "\"7\"");
+    }
+}
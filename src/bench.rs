@@ -0,0 +1,234 @@
+use serde::{Deserialize, Serialize};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fs;
+use std::hint::black_box;
+use std::io;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::Instant;
+
+use crate::error::RunError;
+use crate::solution::Solution;
+
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED_BYTES: AtomicU64 = AtomicU64::new(0);
+
+/// A global allocator that counts allocations so benchmarks can report them. Binaries opt in with:
+///
+/// ```ignore
+/// #[global_allocator]
+/// static ALLOCATOR: CountingAllocator = CountingAllocator;
+/// ```
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        INSTALLED.store(true, Ordering::Relaxed);
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(layout.size() as u64, Ordering::Relaxed);
+
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+fn allocation_counts() -> (u64, u64) {
+    (
+        ALLOCATIONS.load(Ordering::Relaxed),
+        ALLOCATED_BYTES.load(Ordering::Relaxed),
+    )
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Phase {
+    Generator,
+    Part1,
+    Part2,
+}
+
+/// Timings for one phase of one day across every run
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PhaseReport {
+    pub phase: Phase,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub max_ns: u64,
+    /// Allocations made by a single run, if the counting allocator is installed
+    pub allocations: Option<u64>,
+    pub allocated_bytes: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DayReport {
+    pub day: u8,
+    pub phases: Vec<PhaseReport>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Report {
+    pub runs: usize,
+    pub days: Vec<DayReport>,
+}
+
+impl Report {
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Report> {
+        let json = fs::read_to_string(path)?;
+
+        serde_json::from_str(&json).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let json = serde_json::to_string_pretty(self).expect("Reports are always serializable");
+
+        fs::write(path, json + "\n")
+    }
+
+    fn phase(&self, day: u8, phase: Phase) -> Option<&PhaseReport> {
+        self.days
+            .iter()
+            .find(|report| report.day == day)?
+            .phases
+            .iter()
+            .find(|report| report.phase == phase)
+    }
+}
+
+/// A phase whose median time got worse than the allowed threshold since a previous report
+#[derive(Debug, Clone, PartialEq)]
+pub struct Regression {
+    pub day: u8,
+    pub phase: Phase,
+    pub before_ns: u64,
+    pub after_ns: u64,
+}
+
+/// Compares the medians of two reports, flagging every phase that slowed down by more than `threshold` percent.
+pub fn compare(previous: &Report, current: &Report, threshold: f64) -> Vec<Regression> {
+    let mut regressions = Vec::new();
+
+    for day in &current.days {
+        for after in &day.phases {
+            let before = match previous.phase(day.day, after.phase) {
+                Some(before) => before,
+                None => continue,
+            };
+
+            let limit = before.median_ns as f64 * (1.0 + threshold / 100.0);
+            if after.median_ns as f64 > limit {
+                regressions.push(Regression {
+                    day: day.day,
+                    phase: after.phase,
+                    before_ns: before.median_ns,
+                    after_ns: after.median_ns,
+                });
+            }
+        }
+    }
+
+    regressions
+}
+
+/// Runs `f` `runs` times, recording how long each run took and how much the first run allocated.
+fn measure<T, F: FnMut() -> T>(phase: Phase, runs: usize, mut f: F) -> PhaseReport {
+    let mut times = Vec::with_capacity(runs);
+    let mut allocations = None;
+
+    for run in 0..runs.max(1) {
+        let (count_before, bytes_before) = allocation_counts();
+        let start = Instant::now();
+
+        black_box(f());
+
+        times.push(start.elapsed().as_nanos() as u64);
+
+        if run == 0 && INSTALLED.load(Ordering::Relaxed) {
+            let (count_after, bytes_after) = allocation_counts();
+            allocations = Some((count_after - count_before, bytes_after - bytes_before));
+        }
+    }
+
+    times.sort_unstable();
+
+    PhaseReport {
+        phase,
+        min_ns: times[0],
+        median_ns: times[times.len() / 2],
+        max_ns: times[times.len() - 1],
+        allocations: allocations.map(|(count, _)| count),
+        allocated_bytes: allocations.map(|(_, bytes)| bytes),
+    }
+}
+
+/// Benchmarks the generator and both parts of a solution separately. Parts that fail aren't timed.
+pub fn run<S: Solution>(input: &str, runs: usize) -> Result<DayReport, RunError> {
+    let parsed = S::parse(input)?;
+
+    let failed = |error| RunError::Solve { day: S::DAY, error };
+    S::part1(&parsed).map_err(failed)?;
+    S::part2(&parsed).map_err(failed)?;
+
+    let phases = vec![
+        measure(Phase::Generator, runs, || S::parse(input)),
+        measure(Phase::Part1, runs, || S::part1(&parsed)),
+        measure(Phase::Part2, runs, || S::part2(&parsed)),
+    ];
+
+    Ok(DayReport {
+        day: S::DAY,
+        phases,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(median_ns: u64) -> Report {
+        Report {
+            runs: 1,
+            days: vec![DayReport {
+                day: 1,
+                phases: vec![PhaseReport {
+                    phase: Phase::Part1,
+                    min_ns: median_ns,
+                    median_ns,
+                    max_ns: median_ns,
+                    allocations: None,
+                    allocated_bytes: None,
+                }],
+            }],
+        }
+    }
+
+    #[test]
+    fn regressions() {
+        assert!(compare(&report(100), &report(105), 10.0).is_empty());
+        assert_eq!(
+            compare(&report(100), &report(120), 10.0),
+            vec![Regression {
+                day: 1,
+                phase: Phase::Part1,
+                before_ns: 100,
+                after_ns: 120,
+            }]
+        );
+    }
+
+    #[test]
+    fn phases() {
+        let report =
+            run::<crate::years::y2020::day01::Day01>("1721\n979\n366\n299\n675\n1456", 3).unwrap();
+
+        let phases: Vec<Phase> = report.phases.iter().map(|p| p.phase).collect();
+        assert_eq!(phases, vec![Phase::Generator, Phase::Part1, Phase::Part2]);
+        assert!(report
+            .phases
+            .iter()
+            .all(|p| p.min_ns <= p.median_ns && p.median_ns <= p.max_ns));
+    }
+}
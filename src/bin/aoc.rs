@@ -0,0 +1,9 @@
+use aoc_2020::bench::CountingAllocator;
+use std::process;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn main() {
+    process::exit(aoc_2020::cli::main());
+}
@@ -0,0 +1,113 @@
+use std::cell::Cell;
+use std::time::Instant;
+
+use crate::error::SolveError;
+
+/// How often `Budget::tick` looks at the clock. Reading it on every step would dominate tight loops.
+const CLOCK_INTERVAL: u64 = 1024;
+
+/// Limits the runner places on every solver started from the current thread
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct Limits {
+    /// Replaces each solver's own step limit
+    pub max_steps: Option<u64>,
+    /// Solvers give up once this passes
+    pub deadline: Option<Instant>,
+}
+
+thread_local! {
+    static LIMITS: Cell<Limits> = Cell::new(Limits::default());
+}
+
+/// Sets the limits for every solver subsequently run on this thread.
+pub fn set_limits(limits: Limits) {
+    LIMITS.with(|cell| cell.set(limits));
+}
+
+pub fn limits() -> Limits {
+    LIMITS.with(Cell::get)
+}
+
+/// A step counter for loops that aren't guaranteed to terminate on every input. Solvers call `tick` once per
+/// iteration and bail out with the error it returns, rather than hanging.
+#[derive(Debug)]
+pub struct Budget {
+    max_steps: u64,
+    deadline: Option<Instant>,
+    steps: u64,
+}
+
+impl Budget {
+    /// Creates a budget of `max_steps`, unless the runner has set its own limits for this thread.
+    pub fn new(max_steps: u64) -> Budget {
+        let limits = limits();
+
+        Budget {
+            max_steps: limits.max_steps.unwrap_or(max_steps),
+            deadline: limits.deadline,
+            steps: 0,
+        }
+    }
+
+    /// Counts one step, failing once the budget is spent or the deadline has passed.
+    pub fn tick(&mut self) -> Result<(), SolveError> {
+        if self.steps >= self.max_steps {
+            return Err(SolveError::DidNotConverge { steps: self.steps });
+        }
+
+        self.steps += 1;
+
+        if let Some(deadline) = self.deadline {
+            if self.steps.is_multiple_of(CLOCK_INTERVAL) && Instant::now() >= deadline {
+                return Err(SolveError::TimedOut { steps: self.steps });
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn steps(&self) -> u64 {
+        self.steps
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn steps() {
+        let mut budget = Budget::new(3);
+
+        assert!((0..3).all(|_| budget.tick().is_ok()));
+        assert_eq!(budget.tick(), Err(SolveError::DidNotConverge { steps: 3 }));
+    }
+
+    #[test]
+    fn limits_override() {
+        set_limits(Limits {
+            max_steps: Some(1),
+            deadline: Some(Instant::now()),
+        });
+
+        let mut budget = Budget::new(CLOCK_INTERVAL * 2);
+        assert_eq!(budget.tick(), Ok(()));
+        assert_eq!(budget.tick(), Err(SolveError::DidNotConverge { steps: 1 }));
+
+        set_limits(Limits {
+            max_steps: None,
+            deadline: Some(Instant::now()),
+        });
+
+        let mut budget = Budget::new(CLOCK_INTERVAL * 2);
+        let result = (0..CLOCK_INTERVAL).try_for_each(|_| budget.tick());
+        assert_eq!(
+            result,
+            Err(SolveError::TimedOut {
+                steps: CLOCK_INTERVAL
+            })
+        );
+
+        set_limits(Limits::default());
+    }
+}
@@ -0,0 +1,1092 @@
+use clap::{App, AppSettings, Arg, ArgMatches, SubCommand};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::answer::Answer;
+use crate::bench::{self, Report};
+use crate::budget::{self, Limits};
+use crate::client::{self, Client, Config, Ureq, Verdict};
+use crate::input::InputSource;
+use crate::ledger::{self, Ledger, Record};
+use crate::parallel::{self, Summary};
+use crate::params::{self, Params};
+use crate::scaffold;
+use crate::solution::Part;
+use crate::synth;
+use crate::trace;
+use crate::watch::{self, Watcher};
+use crate::years::{self, Year};
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+/// The result of running a single part of a single day
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Outcome {
+    pub day: u8,
+    pub part: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub answer: Option<Answer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Arguments for choosing which days to run, and where their inputs come from
+fn selection_args<'a, 'b>() -> Vec<Arg<'a, 'b>> {
+    vec![
+        year_arg(),
+        Arg::with_name("days")
+            .help("Days to run, e.g. 5, 1-10, 1,3,7 or all")
+            .default_value("all"),
+        Arg::with_name("input")
+            .long("input")
+            .short("i")
+            .takes_value(true)
+            .conflicts_with("input-dir")
+            .help("Input file for a single day, or - to read from stdin"),
+        Arg::with_name("input-dir")
+            .long("input-dir")
+            .takes_value(true)
+            .help("Directory containing dayN.txt input files [default: input/<year>]"),
+    ]
+}
+
+fn year_arg<'a, 'b>() -> Arg<'a, 'b> {
+    Arg::with_name("year")
+        .long("year")
+        .short("y")
+        .takes_value(true)
+        .help("Event year [default: the latest implemented]")
+}
+
+fn format_arg<'a, 'b>() -> Arg<'a, 'b> {
+    Arg::with_name("format")
+        .long("format")
+        .short("f")
+        .takes_value(true)
+        .possible_values(&["text", "json"])
+        .default_value("text")
+}
+
+fn max_steps_arg<'a, 'b>() -> Arg<'a, 'b> {
+    Arg::with_name("max-steps")
+        .long("max-steps")
+        .takes_value(true)
+        .help("Step limit for solvers whose loops might not terminate, instead of their own")
+}
+
+fn part_arg<'a, 'b>() -> Arg<'a, 'b> {
+    Arg::with_name("part")
+        .long("part")
+        .short("p")
+        .takes_value(true)
+        .possible_values(&["1", "2"])
+        .help("Only run this part")
+}
+
+fn ledger_arg<'a, 'b>() -> Arg<'a, 'b> {
+    Arg::with_name("ledger")
+        .long("ledger")
+        .takes_value(true)
+        .default_value(ledger::DEFAULT_PATH)
+        .help("Path to the answer ledger")
+}
+
+/// Arguments for changing the constants days' puzzles are built around
+fn params_args<'a, 'b>() -> Vec<Arg<'a, 'b>> {
+    vec![
+        Arg::with_name("params")
+            .long("params")
+            .takes_value(true)
+            .default_value(params::DEFAULT_PATH)
+            .help("File of [dayNN] tables of puzzle parameters, used if it exists"),
+        Arg::with_name("param")
+            .long("param")
+            .short("P")
+            .takes_value(true)
+            .multiple(true)
+            .number_of_values(1)
+            .help("Sets a puzzle parameter, e.g. 9.preamble=5, overriding the file"),
+    ]
+}
+
+fn config_arg<'a, 'b>() -> Arg<'a, 'b> {
+    Arg::with_name("config")
+        .long("config")
+        .short("c")
+        .takes_value(true)
+        .default_value(client::DEFAULT_CONFIG)
+        .help("Config file holding the session token")
+}
+
+fn app<'a, 'b>() -> App<'a, 'b> {
+    App::new("aoc")
+        .about("Runs Advent of Code solutions")
+        .setting(AppSettings::SubcommandRequiredElseHelp)
+        .subcommand(
+            SubCommand::with_name("run")
+                .about("Runs one or more days")
+                .args(&selection_args())
+                .args(&params_args())
+                .arg(part_arg())
+                .arg(format_arg())
+                .arg(max_steps_arg())
+                .arg(
+                    Arg::with_name("trace")
+                        .long("trace")
+                        .takes_value(true)
+                        .help("Days whose solvers should explain what they're doing, e.g. 8 or 7,11"),
+                )
+                .arg(
+                    Arg::with_name("trace-file")
+                        .long("trace-file")
+                        .takes_value(true)
+                        .requires("trace")
+                        .help("File to write the trace to [default: stderr]"),
+                )
+                .arg(
+                    Arg::with_name("timeout")
+                        .long("timeout")
+                        .short("t")
+                        .takes_value(true)
+                        .help("Seconds each part may run for before giving up"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("all")
+                .about("Runs both parts of several days concurrently and summarises them")
+                .args(&selection_args())
+                .args(&params_args())
+                .arg(format_arg())
+                .arg(
+                    Arg::with_name("threads")
+                        .long("threads")
+                        .short("j")
+                        .takes_value(true)
+                        .help("How many days to run at once [default: one per CPU]"),
+                )
+                .arg(
+                    Arg::with_name("timeout")
+                        .long("timeout")
+                        .short("t")
+                        .takes_value(true)
+                        .default_value("60")
+                        .help("Seconds a day may run for before it's reported as timed out"),
+                )
+                .arg(max_steps_arg()),
+        )
+        .subcommand(
+            SubCommand::with_name("check")
+                .about("Lists every malformed line in the inputs of one or more days, without solving them")
+                .args(&selection_args()),
+        )
+        .subcommand(
+            SubCommand::with_name("bench")
+                .about("Times the generator and each part of one or more days")
+                .args(&selection_args())
+                .arg(
+                    Arg::with_name("runs")
+                        .long("runs")
+                        .short("n")
+                        .takes_value(true)
+                        .default_value("10")
+                        .help("How many times to run each phase"),
+                )
+                .arg(
+                    Arg::with_name("output")
+                        .long("output")
+                        .short("o")
+                        .takes_value(true)
+                        .help("Write a JSON report to this file"),
+                )
+                .arg(
+                    Arg::with_name("baseline")
+                        .long("baseline")
+                        .short("b")
+                        .takes_value(true)
+                        .help("A previous JSON report to check for regressions against"),
+                )
+                .arg(
+                    Arg::with_name("threshold")
+                        .long("threshold")
+                        .takes_value(true)
+                        .default_value("10")
+                        .help(
+                            "How many percent slower a median has to get to count as a regression",
+                        ),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("new")
+                .about("Creates the module, registry entries, input file and fixtures directory for a new day")
+                .arg(
+                    Arg::with_name("day")
+                        .required(true)
+                        .help("Day to create"),
+                )
+                .arg(year_arg())
+                .arg(
+                    Arg::with_name("root")
+                        .long("root")
+                        .takes_value(true)
+                        .default_value(".")
+                        .help("Repository to add the day to"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("synth")
+                .about("Generates an input of any size for a day, along with the answers it was built to have")
+                .arg(
+                    Arg::with_name("day")
+                        .required(true)
+                        .help("Day to generate an input for"),
+                )
+                .arg(year_arg())
+                .arg(
+                    Arg::with_name("size")
+                        .long("size")
+                        .short("s")
+                        .takes_value(true)
+                        .default_value("1000")
+                        .help("Roughly how many lines, records or rows to generate, capped per day"),
+                )
+                .arg(
+                    Arg::with_name("seed")
+                        .long("seed")
+                        .takes_value(true)
+                        .default_value("0")
+                        .help("The same seed and size always generate the same input"),
+                )
+                .arg(
+                    Arg::with_name("output")
+                        .long("output")
+                        .short("o")
+                        .takes_value(true)
+                        .help("Write the input to this file instead of stdout"),
+                )
+                .arg(
+                    Arg::with_name("verify")
+                        .long("verify")
+                        .help("Run the day's solver on the input and fail if its answers differ"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("watch")
+                .about("Re-runs a day's examples and input whenever its source, fixtures or input change")
+                .args(&selection_args())
+                .arg(
+                    Arg::with_name("root")
+                        .long("root")
+                        .takes_value(true)
+                        .default_value(".")
+                        .help("Repository the day lives in"),
+                )
+                .arg(
+                    Arg::with_name("release")
+                        .long("release")
+                        .help("Build in release mode, for days that are slow without optimisations"),
+                )
+                .arg(
+                    Arg::with_name("interval")
+                        .long("interval")
+                        .takes_value(true)
+                        .default_value("500")
+                        .help("Milliseconds between checks for changes"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("fetch")
+                .about("Downloads the inputs of one or more days, skipping any that are already cached")
+                .arg(
+                    Arg::with_name("days")
+                        .help("Days to fetch, e.g. 5, 1-10, 1,3,7 or all")
+                        .default_value("all"),
+                )
+                .arg(year_arg())
+                .arg(config_arg()),
+        )
+        .subcommand(
+            SubCommand::with_name("submit")
+                .about("Submits an answer, solving the day's cached input if no answer is given")
+                .arg(Arg::with_name("day").required(true).help("Day to answer"))
+                .arg(
+                    Arg::with_name("part")
+                        .required(true)
+                        .possible_values(&["1", "2"])
+                        .help("Part to answer"),
+                )
+                .arg(Arg::with_name("answer").help("The answer to submit"))
+                .arg(year_arg())
+                .arg(config_arg()),
+        )
+        .subcommand(
+            SubCommand::with_name("ledger")
+                .about("Records verified answers and checks solvers still produce them")
+                .setting(AppSettings::SubcommandRequiredElseHelp)
+                .subcommand(
+                    SubCommand::with_name("record")
+                        .about("Records the current answers as verified")
+                        .args(&selection_args())
+                        .arg(part_arg())
+                        .arg(ledger_arg()),
+                )
+                .subcommand(
+                    SubCommand::with_name("verify")
+                        .about("Re-runs every recorded answer and fails on any drift")
+                        .arg(ledger_arg()),
+                ),
+        )
+}
+
+/// Entry point for the `aoc` binary, returning the process exit code.
+pub fn main() -> i32 {
+    let matches = app().get_matches();
+
+    let result = match matches.subcommand() {
+        ("run", Some(matches)) => run(matches),
+        ("all", Some(matches)) => all(matches),
+        ("bench", Some(matches)) => bench(matches),
+        ("check", Some(matches)) => check(matches),
+        ("new", Some(matches)) => new_day(matches),
+        ("synth", Some(matches)) => synth(matches),
+        ("watch", Some(matches)) => watch(matches),
+        ("fetch", Some(matches)) => fetch(matches),
+        ("submit", Some(matches)) => submit(matches),
+        ("ledger", Some(matches)) => match matches.subcommand() {
+            ("record", Some(matches)) => ledger_record(matches),
+            ("verify", Some(matches)) => ledger_verify(matches),
+            _ => unreachable!("clap requires a subcommand"),
+        },
+        _ => unreachable!("clap requires a subcommand"),
+    };
+
+    match result {
+        Ok(code) => code,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            2
+        }
+    }
+}
+
+fn year(matches: &ArgMatches) -> Result<&'static Year, String> {
+    match matches.value_of("year") {
+        Some(year) => year
+            .parse::<u16>()
+            .ok()
+            .and_then(years::get)
+            .ok_or_else(|| format!("Year {} isn't implemented", year)),
+        None => Ok(years::latest()),
+    }
+}
+
+type Selection = (&'static Year, Vec<u8>, Vec<Part>, InputSource);
+
+fn selection(matches: &ArgMatches) -> Result<Selection, String> {
+    let year = year(matches)?;
+    let days = parse_days(year, matches.value_of("days").unwrap_or("all"))?;
+
+    let parts = match matches.value_of("part") {
+        Some("1") => vec![Part::One],
+        Some("2") => vec![Part::Two],
+        _ => vec![Part::One, Part::Two],
+    };
+
+    let source = match matches.value_of("input") {
+        Some("-") => InputSource::Stdin,
+        Some(path) => InputSource::File(PathBuf::from(path)),
+        None => InputSource::Dir(match matches.value_of("input-dir") {
+            Some(dir) => PathBuf::from(dir),
+            None => PathBuf::from("input").join(year.year.to_string()),
+        }),
+    };
+
+    if days.len() > 1 && matches!(source, InputSource::File(_) | InputSource::Stdin) {
+        return Err("--input can only be used when running a single day".to_owned());
+    }
+
+    Ok((year, days, parts, source))
+}
+
+fn params(matches: &ArgMatches, year: &Year) -> Result<Params, String> {
+    let mut params = Params::load(matches.value_of("params").unwrap_or(params::DEFAULT_PATH))
+        .map_err(|e| e.to_string())?;
+
+    for assignment in matches.values_of("param").into_iter().flatten() {
+        params.apply(assignment).map_err(|e| e.to_string())?;
+    }
+
+    params.check(year).map_err(|e| e.to_string())?;
+    Ok(params)
+}
+
+fn max_steps(matches: &ArgMatches) -> Result<Option<u64>, String> {
+    matches
+        .value_of("max-steps")
+        .map(|steps| {
+            steps
+                .parse::<u64>()
+                .map_err(|_| "--max-steps must be a number".to_owned())
+        })
+        .transpose()
+}
+
+fn timeout(matches: &ArgMatches) -> Result<Option<Duration>, String> {
+    matches
+        .value_of("timeout")
+        .map(|secs| {
+            secs.parse::<f64>()
+                .map(Duration::from_secs_f64)
+                .map_err(|_| "--timeout must be a number of seconds".to_owned())
+        })
+        .transpose()
+}
+
+fn run(matches: &ArgMatches) -> Result<i32, String> {
+    let (year, days, parts, source) = selection(matches)?;
+
+    let format = match matches.value_of("format") {
+        Some("json") => Format::Json,
+        _ => Format::Text,
+    };
+    let max_steps = max_steps(matches)?;
+    let timeout = timeout(matches)?;
+
+    budget::set_limits(Limits {
+        max_steps,
+        deadline: None,
+    });
+    params::set_params(params(matches, year)?);
+
+    if let Some(traced) = matches.value_of("trace") {
+        let traced = parse_days(year, traced)?;
+        let sink: Box<dyn Write> = match matches.value_of("trace-file") {
+            Some(path) => Box::new(BufWriter::new(
+                fs::File::create(path).map_err(|e| format!("Unable to create {}: {}", path, e))?,
+            )),
+            None => Box::new(io::stderr()),
+        };
+
+        trace::enable(&traced, sink);
+    }
+
+    let outcomes = solve(year, &days, &parts, &source, timeout);
+    trace::disable();
+
+    print(&outcomes, format);
+
+    let failed = outcomes.iter().any(|outcome| outcome.error.is_some());
+    Ok(if failed { 1 } else { 0 })
+}
+
+fn all(matches: &ArgMatches) -> Result<i32, String> {
+    let (year, days, _, source) = selection(matches)?;
+
+    let format = match matches.value_of("format") {
+        Some("json") => Format::Json,
+        _ => Format::Text,
+    };
+    let threads = match matches.value_of("threads") {
+        Some(threads) => threads
+            .parse::<usize>()
+            .map_err(|_| "--threads must be a number")?,
+        None => thread::available_parallelism().map_or(1, |n| n.get()),
+    };
+    let timeout = timeout(matches)?.expect("--timeout has a default");
+
+    let summaries = parallel::run(
+        year,
+        &days,
+        &source,
+        threads,
+        timeout,
+        max_steps(matches)?,
+        &params(matches, year)?,
+    );
+    print_summaries(&summaries, format);
+
+    let failed = summaries.iter().any(|summary| summary.error.is_some());
+    Ok(if failed { 1 } else { 0 })
+}
+
+fn bench(matches: &ArgMatches) -> Result<i32, String> {
+    let (year, days, _, source) = selection(matches)?;
+
+    let runs = matches
+        .value_of("runs")
+        .unwrap_or_default()
+        .parse::<usize>()
+        .map_err(|_| "--runs must be a number")?;
+    let threshold = matches
+        .value_of("threshold")
+        .unwrap_or_default()
+        .parse::<f64>()
+        .map_err(|_| "--threshold must be a number")?;
+
+    let mut report = Report {
+        runs,
+        days: Vec::new(),
+    };
+    let mut failed = false;
+
+    println!(
+        "{:<4} {:<10} {:>14} {:>14} {:>14} {:>12}",
+        "day", "phase", "min", "median", "max", "allocations"
+    );
+
+    for day in days {
+        let entry = year
+            .get(day)
+            .expect("Days are validated against the registry");
+
+        let result = source
+            .read(day)
+            .map_err(|e| format!("Unable to read input: {}", e))
+            .and_then(|input| entry.bench(&input, runs).map_err(|e| e.to_string()));
+
+        match result {
+            Ok(day_report) => {
+                for phase in &day_report.phases {
+                    println!(
+                        "{:<4} {:<10} {:>14} {:>14} {:>14} {:>12}",
+                        day,
+                        format!("{:?}", phase.phase).to_lowercase(),
+                        format!("{:?}", Duration::from_nanos(phase.min_ns)),
+                        format!("{:?}", Duration::from_nanos(phase.median_ns)),
+                        format!("{:?}", Duration::from_nanos(phase.max_ns)),
+                        phase
+                            .allocations
+                            .map_or("-".to_owned(), |allocations| allocations.to_string()),
+                    );
+                }
+
+                report.days.push(day_report);
+            }
+            Err(e) => {
+                failed = true;
+                println!("{:<4} FAILED: {}", day, e);
+            }
+        }
+    }
+
+    if let Some(path) = matches.value_of("output") {
+        report
+            .save(path)
+            .map_err(|e| format!("Unable to save {}: {}", path, e))?;
+    }
+
+    if let Some(path) = matches.value_of("baseline") {
+        let baseline = Report::load(path).map_err(|e| format!("Unable to load {}: {}", path, e))?;
+
+        for regression in bench::compare(&baseline, &report, threshold) {
+            failed = true;
+            println!(
+                "REGRESSION: day {} {:?} went from {:?} to {:?}",
+                regression.day,
+                regression.phase,
+                Duration::from_nanos(regression.before_ns),
+                Duration::from_nanos(regression.after_ns)
+            );
+        }
+    }
+
+    Ok(if failed { 1 } else { 0 })
+}
+
+fn check(matches: &ArgMatches) -> Result<i32, String> {
+    let (year, days, _, source) = selection(matches)?;
+    let mut failed = false;
+
+    for day in days {
+        let entry = year
+            .get(day)
+            .expect("Days are validated against the registry");
+
+        let errors = match source.read(day) {
+            Ok(input) => entry.check(&input),
+            Err(e) => {
+                failed = true;
+                println!("Day {}: FAILED: Unable to read input: {}", day, e);
+                continue;
+            }
+        };
+
+        for error in &errors {
+            println!("{}", error);
+        }
+
+        failed |= !errors.is_empty();
+        println!(
+            "Day {}: {} {}",
+            day,
+            errors.len(),
+            if errors.len() == 1 {
+                "problem"
+            } else {
+                "problems"
+            }
+        );
+    }
+
+    Ok(if failed { 1 } else { 0 })
+}
+
+fn new_day(matches: &ArgMatches) -> Result<i32, String> {
+    let day = matches
+        .value_of("day")
+        .unwrap_or_default()
+        .parse::<u8>()
+        .map_err(|_| "The day must be a number")?;
+    let root = PathBuf::from(matches.value_of("root").unwrap_or_default());
+
+    let year = match matches.value_of("year") {
+        Some(year) => year.parse::<u16>().map_err(|_| "--year must be a number")?,
+        None => years::latest().year,
+    };
+
+    let created = scaffold::scaffold(&root, year, day).map_err(|e| e.to_string())?;
+
+    for path in created {
+        println!("{}", path.display());
+    }
+
+    Ok(0)
+}
+
+fn synth(matches: &ArgMatches) -> Result<i32, String> {
+    let year = year(matches)?;
+    let day = matches.value_of("day").unwrap_or_default();
+    let generator = day
+        .parse::<u8>()
+        .ok()
+        .and_then(|day| synth::get(year.year, day))
+        .ok_or_else(|| format!("Day {} of {} has no input generator", day, year.year))?;
+
+    let size = matches
+        .value_of("size")
+        .unwrap_or_default()
+        .parse::<usize>()
+        .map_err(|_| "--size must be a number")?;
+    let seed = matches
+        .value_of("seed")
+        .unwrap_or_default()
+        .parse::<u64>()
+        .map_err(|_| "--seed must be a number")?;
+
+    if size > generator.max_size {
+        eprintln!(
+            "Day {} inputs are capped at a size of {}",
+            generator.day, generator.max_size
+        );
+    }
+
+    let synthetic = generator.generate(size, seed);
+
+    match matches.value_of("output") {
+        Some(path) => fs::write(path, &synthetic.input)
+            .map_err(|e| format!("Unable to write {}: {}", path, e))?,
+        None => println!("{}", synthetic.input),
+    }
+
+    // Answers go to stderr so the input can be piped straight into `aoc run -i -`
+    eprintln!("Day {} - Part 1: {}", generator.day, synthetic.part1);
+    eprintln!("Day {} - Part 2: {}", generator.day, synthetic.part2);
+
+    if !matches.is_present("verify") {
+        return Ok(0);
+    }
+
+    let entry = year.get(generator.day).expect("Every generator has a day");
+    let mut failed = false;
+
+    let parts = [
+        (1, Part::One, &synthetic.part1),
+        (2, Part::Two, &synthetic.part2),
+    ];
+
+    for (number, part, expected) in parts {
+        match entry.run(&synthetic.input, part) {
+            Ok(answer) if &answer == expected => (),
+            Ok(answer) => {
+                failed = true;
+                eprintln!(
+                    "Day {} - Part {}: MISMATCH: the solver gave {}",
+                    generator.day, number, answer
+                );
+            }
+            Err(e) => {
+                failed = true;
+                eprintln!("Day {} - Part {}: FAILED: {}", generator.day, number, e);
+            }
+        }
+    }
+
+    if !failed {
+        eprintln!("Solver answers match");
+    }
+
+    Ok(if failed { 1 } else { 0 })
+}
+
+fn watch(matches: &ArgMatches) -> Result<i32, String> {
+    let (year, days, _, source) = selection(matches)?;
+    let day = match days[..] {
+        [day] => day,
+        _ => return Err("Only one day can be watched at a time".to_owned()),
+    };
+    let interval = matches
+        .value_of("interval")
+        .unwrap_or_default()
+        .parse::<u64>()
+        .map(Duration::from_millis)
+        .map_err(|_| "--interval must be a number of milliseconds")?;
+
+    let root = PathBuf::from(matches.value_of("root").unwrap_or_default());
+    let cargo = watch::Cargo {
+        manifest: root.join("Cargo.toml"),
+        release: matches.is_present("release"),
+    };
+    let mut watcher = Watcher::new(watch::paths(&root, year.year, day, source.path(day)));
+    let mut previous = Vec::new();
+
+    loop {
+        match cargo.examples(year.year, day) {
+            Ok(()) => println!("Day {}: examples passed", day),
+            Err(output) => println!("Day {}: examples FAILED\n{}", day, output.trim_end()),
+        }
+
+        match cargo.answers(year.year, day, &source) {
+            Ok(outcomes) => {
+                for diff in watch::diff(&previous, &outcomes) {
+                    println!("{}", diff);
+                }
+                previous = outcomes;
+            }
+            Err(e) => println!("Day {}: FAILED\n{}", day, e.trim_end()),
+        }
+
+        let changes = loop {
+            thread::sleep(interval);
+
+            let changes = watcher.changes();
+            if !changes.is_empty() {
+                break changes;
+            }
+        };
+
+        println!();
+        for path in changes {
+            println!("Changed: {}", path.display());
+        }
+    }
+}
+
+fn client(matches: &ArgMatches) -> Result<Client<Ureq>, String> {
+    let path = matches.value_of("config").unwrap_or(client::DEFAULT_CONFIG);
+    let config = Config::load(path).map_err(|e| e.to_string())?;
+
+    Ok(Client::new(Ureq::new(), config))
+}
+
+fn fetch(matches: &ArgMatches) -> Result<i32, String> {
+    let year = year(matches)?;
+    let days = parse_days(year, matches.value_of("days").unwrap_or("all"))?;
+    let client = client(matches)?;
+    let mut failed = false;
+
+    for day in days {
+        match client.input(year.year, day) {
+            Ok(_) => println!(
+                "Day {}: {}",
+                day,
+                client.input_path(year.year, day).display()
+            ),
+            Err(e) => {
+                failed = true;
+                println!("Day {}: FAILED: {}", day, e);
+            }
+        }
+    }
+
+    Ok(if failed { 1 } else { 0 })
+}
+
+fn submit(matches: &ArgMatches) -> Result<i32, String> {
+    let year = year(matches)?;
+    let day = matches.value_of("day").unwrap_or_default();
+    let entry = day
+        .parse::<u8>()
+        .ok()
+        .and_then(|day| year.get(day))
+        .ok_or_else(|| format!("Day {} of {} isn't implemented", day, year.year))?;
+    let part = match matches.value_of("part") {
+        Some("1") => Part::One,
+        _ => Part::Two,
+    };
+    let client = client(matches)?;
+
+    let answer = match matches.value_of("answer") {
+        Some(answer) => Answer::from(answer),
+        None => {
+            let input = client
+                .input(year.year, entry.day)
+                .map_err(|e| e.to_string())?;
+            entry.run(&input, part).map_err(|e| e.to_string())?
+        }
+    };
+
+    let verdict = client
+        .submit(year.year, entry.day, part, &answer.to_string())
+        .map_err(|e| e.to_string())?;
+    println!("{}: {}", answer, verdict);
+
+    Ok(if verdict == Verdict::Correct { 0 } else { 1 })
+}
+
+fn ledger_record(matches: &ArgMatches) -> Result<i32, String> {
+    let (year, days, parts, source) = selection(matches)?;
+    let path = matches.value_of("ledger").unwrap_or(ledger::DEFAULT_PATH);
+
+    if source == InputSource::Stdin {
+        return Err("Answers read from stdin can't be recorded".to_owned());
+    }
+
+    let mut ledger = Ledger::load(path).map_err(|e| format!("Unable to load {}: {}", path, e))?;
+    let mut failed = false;
+
+    for outcome in solve(year, &days, &parts, &source, None) {
+        let input = source.path(outcome.day).expect("Input comes from a file");
+
+        match outcome.answer {
+            Some(answer) => {
+                println!(
+                    "Day {} - Part {}: recorded {}",
+                    outcome.day, outcome.part, answer
+                );
+
+                ledger.record(Record {
+                    year: year.year,
+                    day: outcome.day,
+                    part: outcome.part,
+                    input,
+                    answer,
+                });
+            }
+            None => {
+                failed = true;
+                println!(
+                    "Day {} - Part {}: FAILED: {}",
+                    outcome.day,
+                    outcome.part,
+                    outcome.error.unwrap_or_default()
+                );
+            }
+        }
+    }
+
+    ledger
+        .save(path)
+        .map_err(|e| format!("Unable to save {}: {}", path, e))?;
+
+    Ok(if failed { 1 } else { 0 })
+}
+
+fn ledger_verify(matches: &ArgMatches) -> Result<i32, String> {
+    let path = matches.value_of("ledger").unwrap_or(ledger::DEFAULT_PATH);
+    let ledger = Ledger::load(path).map_err(|e| format!("Unable to load {}: {}", path, e))?;
+
+    let drift = ledger.verify();
+
+    for drift in &drift {
+        let record = &drift.record;
+        let actual = match &drift.actual {
+            Ok(answer) => answer.to_string(),
+            Err(e) => format!("error: {}", e),
+        };
+
+        println!(
+            "Day {} - Part {} ({}): DRIFT: expected {}, got {}",
+            record.day,
+            record.part,
+            record.input.display(),
+            record.answer,
+            actual
+        );
+    }
+
+    println!(
+        "{} of {} recorded answers match",
+        ledger.records().len() - drift.len(),
+        ledger.records().len()
+    );
+
+    Ok(if drift.is_empty() { 0 } else { 1 })
+}
+
+/// Runs the given parts of every day of `year`, collecting answers and errors rather than stopping at the first failure, even
+/// when a part panics. Each part gets `timeout` to finish in, on top of the limits already set for this thread.
+pub fn solve(
+    year: &Year,
+    days: &[u8],
+    parts: &[Part],
+    source: &InputSource,
+    timeout: Option<Duration>,
+) -> Vec<Outcome> {
+    let mut outcomes = Vec::new();
+
+    for &day in days {
+        let entry = year
+            .get(day)
+            .expect("Days are validated against the registry");
+        let input = source.read(day);
+
+        for &part in parts {
+            let part_number = match part {
+                Part::One => 1,
+                Part::Two => 2,
+            };
+
+            budget::set_limits(Limits {
+                deadline: timeout.map(|timeout| Instant::now() + timeout),
+                ..budget::limits()
+            });
+
+            let result = match &input {
+                Ok(input) => entry.try_run(input, part).map_err(|e| e.to_string()),
+                Err(e) => Err(format!("Unable to read input: {}", e)),
+            };
+
+            outcomes.push(Outcome {
+                day,
+                part: part_number,
+                answer: result.as_ref().ok().cloned(),
+                error: result.err(),
+            });
+        }
+    }
+
+    outcomes
+}
+
+fn print(outcomes: &[Outcome], format: Format) {
+    match format {
+        Format::Text => {
+            for outcome in outcomes {
+                match (&outcome.answer, &outcome.error) {
+                    (Some(answer), _) => {
+                        println!("Day {} - Part {}: {}", outcome.day, outcome.part, answer)
+                    }
+                    (_, Some(error)) => println!(
+                        "Day {} - Part {}: FAILED: {}",
+                        outcome.day, outcome.part, error
+                    ),
+                    _ => unreachable!(),
+                }
+            }
+        }
+        Format::Json => {
+            let json =
+                serde_json::to_string_pretty(outcomes).expect("Outcomes are always serializable");
+            println!("{}", json);
+        }
+    }
+}
+
+fn print_summaries(summaries: &[Summary], format: Format) {
+    match format {
+        Format::Text => {
+            println!(
+                "{:<4} {:>20} {:>20} {:>12}  error",
+                "day", "part 1", "part 2", "time"
+            );
+
+            for summary in summaries {
+                println!(
+                    "{:<4} {:>20} {:>20} {:>12}  {}",
+                    summary.day,
+                    summary
+                        .part1
+                        .as_ref()
+                        .map_or("-".to_owned(), Answer::to_string),
+                    summary
+                        .part2
+                        .as_ref()
+                        .map_or("-".to_owned(), Answer::to_string),
+                    format!("{:.3}ms", summary.elapsed_ms),
+                    summary.error.as_deref().unwrap_or_default()
+                );
+            }
+
+            let total: f64 = summaries.iter().map(|summary| summary.elapsed_ms).sum();
+            let failed = summaries.iter().filter(|s| s.error.is_some()).count();
+            println!(
+                "{} days, {} failed, {:.3}ms of solver time",
+                summaries.len(),
+                failed,
+                total
+            );
+        }
+        Format::Json => {
+            let json =
+                serde_json::to_string_pretty(summaries).expect("Summaries are always serializable");
+            println!("{}", json);
+        }
+    }
+}
+
+/// Parses a day selection like `5`, `1-10`, `1,3,7-9` or `all` into the days of `year` it covers.
+pub fn parse_days(year: &Year, s: &str) -> Result<Vec<u8>, String> {
+    if s == "all" {
+        return Ok(year.days.iter().map(|entry| entry.day).collect());
+    }
+
+    let mut selected = Vec::new();
+
+    for range in s.split(',') {
+        let parse = |n: &str| {
+            n.trim()
+                .parse::<u8>()
+                .map_err(|_| format!("Invalid day: {}", n))
+        };
+
+        let (start, end) = match range.find('-') {
+            Some(i) => (parse(&range[..i])?, parse(&range[i + 1..])?),
+            None => (parse(range)?, parse(range)?),
+        };
+
+        for day in start..=end {
+            if year.get(day).is_none() {
+                return Err(format!("Day {} of {} isn't implemented", day, year.year));
+            }
+
+            if !selected.contains(&day) {
+                selected.push(day);
+            }
+        }
+    }
+
+    Ok(selected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn days() {
+        let year = years::get(2020).unwrap();
+
+        assert_eq!(parse_days(year, "5"), Ok(vec![5]));
+        assert_eq!(parse_days(year, "1-3,7"), Ok(vec![1, 2, 3, 7]));
+        assert_eq!(parse_days(year, "all").unwrap().len(), year.days.len());
+        assert!(parse_days(year, "26").is_err());
+        assert!(parse_days(year, "one").is_err());
+    }
+}
@@ -0,0 +1,521 @@
+use serde::Deserialize;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::solution::Part;
+
+/// Where the session token lives. It's a login credential, so the file is ignored by git.
+pub const DEFAULT_CONFIG: &str = "aoc.toml";
+
+const USER_AGENT: &str = concat!("aoc-2020/", env!("CARGO_PKG_VERSION"));
+
+#[derive(Debug)]
+pub enum ClientError {
+    /// The config has no session token, and one is needed to talk to the site
+    NoSession,
+    /// The site answered, but not with a success
+    Status(u16, String),
+    /// The site couldn't be reached at all
+    Transport(String),
+    Io(PathBuf, io::Error),
+    Config(PathBuf, String),
+    /// A submission's response didn't match any of the known outcomes
+    UnrecognizedResponse(String),
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ClientError::NoSession => write!(f, "No session token, set one in {}", DEFAULT_CONFIG),
+            ClientError::Status(400, _) => write!(f, "The session token was rejected"),
+            ClientError::Status(404, _) => write!(f, "That puzzle isn't unlocked yet"),
+            ClientError::Status(status, body) => write!(f, "HTTP {}: {}", status, body.trim()),
+            ClientError::Transport(e) => write!(f, "Unable to reach the site: {}", e),
+            ClientError::Io(path, e) => write!(f, "{}: {}", path.display(), e),
+            ClientError::Config(path, e) => write!(f, "Invalid config {}: {}", path.display(), e),
+            ClientError::UnrecognizedResponse(_) => {
+                write!(f, "Unrecognized response to the answer")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+/// The only HTTP the client needs. `Ureq` talks to the real site; anything else can stand in for it.
+pub trait Http {
+    /// Fetches `url`, returning the body of a successful response
+    fn get(&self, url: &str, session: &str) -> Result<String, ClientError>;
+    /// Posts `form` to `url` as `application/x-www-form-urlencoded`, returning the body of a successful response
+    fn post(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<String, ClientError>;
+}
+
+pub struct Ureq {
+    agent: ureq::Agent,
+}
+
+impl Ureq {
+    pub fn new() -> Ureq {
+        Ureq {
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+        }
+    }
+
+    fn body(result: Result<ureq::Response, ureq::Error>) -> Result<String, ClientError> {
+        let response = match result {
+            Ok(response) => response,
+            Err(ureq::Error::Status(status, response)) => {
+                return Err(ClientError::Status(
+                    status,
+                    response.into_string().unwrap_or_default(),
+                ))
+            }
+            Err(ureq::Error::Transport(e)) => return Err(ClientError::Transport(e.to_string())),
+        };
+
+        response
+            .into_string()
+            .map_err(|e| ClientError::Transport(e.to_string()))
+    }
+}
+
+impl Default for Ureq {
+    fn default() -> Ureq {
+        Ureq::new()
+    }
+}
+
+impl Http for Ureq {
+    fn get(&self, url: &str, session: &str) -> Result<String, ClientError> {
+        let request = self
+            .agent
+            .get(url)
+            .set("Cookie", &format!("session={}", session));
+
+        Ureq::body(request.call())
+    }
+
+    fn post(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<String, ClientError> {
+        let request = self
+            .agent
+            .post(url)
+            .set("Cookie", &format!("session={}", session));
+
+        Ureq::body(request.send_form(form))
+    }
+}
+
+fn default_base_url() -> String {
+    "https://adventofcode.com".to_owned()
+}
+
+fn default_cache_dir() -> PathBuf {
+    PathBuf::from("input")
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Config {
+    /// The `session` cookie of a logged in browser
+    #[serde(default)]
+    pub session: Option<String>,
+    #[serde(default = "default_base_url")]
+    pub base_url: String,
+    /// Downloaded inputs go in `<cache_dir>/<year>/dayN.txt`, the same layout the runner reads from
+    #[serde(default = "default_cache_dir")]
+    pub cache_dir: PathBuf,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            session: None,
+            base_url: default_base_url(),
+            cache_dir: default_cache_dir(),
+        }
+    }
+}
+
+impl Config {
+    /// Loads a config, treating a missing file as the defaults. `AOC_SESSION` takes precedence over the file's token.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Config, ClientError> {
+        let path = path.as_ref();
+
+        let mut config = match fs::read_to_string(path) {
+            Ok(toml) => toml::from_str(&toml)
+                .map_err(|e| ClientError::Config(path.to_owned(), e.to_string()))?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Config::default(),
+            Err(e) => return Err(ClientError::Io(path.to_owned(), e)),
+        };
+
+        if let Ok(session) = std::env::var("AOC_SESSION") {
+            config.session = Some(session);
+        }
+
+        Ok(config)
+    }
+}
+
+/// What the site made of a submitted answer
+#[derive(Debug, Clone, PartialEq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint as to which way
+    Incorrect,
+    /// Submitted too soon after a wrong answer; try again after this long
+    Wait(Option<Duration>),
+    /// The part was already solved, or part 2 isn't unlocked yet
+    WrongLevel,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "Correct"),
+            Verdict::TooHigh => write!(f, "Too high"),
+            Verdict::TooLow => write!(f, "Too low"),
+            Verdict::Incorrect => write!(f, "Incorrect"),
+            Verdict::Wait(Some(wait)) => write!(f, "Wait {:?} before answering again", wait),
+            Verdict::Wait(None) => write!(f, "Wait before answering again"),
+            Verdict::WrongLevel => write!(f, "Already solved, or not unlocked yet"),
+        }
+    }
+}
+
+/// Reads the verdict out of the page the site returns for a submission.
+pub fn parse_verdict(page: &str) -> Result<Verdict, ClientError> {
+    if page.contains("That's the right answer") {
+        Ok(Verdict::Correct)
+    } else if page.contains("You gave an answer too recently") {
+        Ok(Verdict::Wait(parse_wait(page)))
+    } else if page.contains("your answer is too high") {
+        Ok(Verdict::TooHigh)
+    } else if page.contains("your answer is too low") {
+        Ok(Verdict::TooLow)
+    } else if page.contains("That's not the right answer") {
+        Ok(Verdict::Incorrect)
+    } else if page.contains("You don't seem to be solving the right level") {
+        Ok(Verdict::WrongLevel)
+    } else {
+        Err(ClientError::UnrecognizedResponse(page.to_owned()))
+    }
+}
+
+/// Parses the "You have 1m 5s left to wait" part of a rate limited response
+fn parse_wait(page: &str) -> Option<Duration> {
+    let start = page.find("You have ")? + "You have ".len();
+    let end = start + page[start..].find(" left to wait")?;
+
+    page[start..end]
+        .split_whitespace()
+        .map(|amount| {
+            let (number, unit) = amount.split_at(amount.find(|c: char| !c.is_ascii_digit())?);
+            let number = number.parse::<u64>().ok()?;
+
+            match unit {
+                "h" => Some(number * 3600),
+                "m" => Some(number * 60),
+                "s" => Some(number),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+/// Downloads inputs and submits answers for a logged in user
+pub struct Client<H: Http> {
+    http: H,
+    config: Config,
+}
+
+impl<H: Http> Client<H> {
+    pub fn new(http: H, config: Config) -> Client<H> {
+        Client { http, config }
+    }
+
+    fn session(&self) -> Result<&str, ClientError> {
+        self.config.session.as_deref().ok_or(ClientError::NoSession)
+    }
+
+    /// Where a day's input is cached
+    pub fn input_path(&self, year: u16, day: u8) -> PathBuf {
+        self.config
+            .cache_dir
+            .join(year.to_string())
+            .join(format!("day{}.txt", day))
+    }
+
+    /// A day's input, downloading it only if it isn't already cached. Inputs never change, so there's no reason to ask
+    /// the site twice.
+    pub fn input(&self, year: u16, day: u8) -> Result<String, ClientError> {
+        let path = self.input_path(year, day);
+
+        // A previous failed download could have left an empty file behind
+        match fs::read_to_string(&path) {
+            Ok(input) if !input.is_empty() => return Ok(input),
+            Ok(_) => (),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => (),
+            Err(e) => return Err(ClientError::Io(path, e)),
+        }
+
+        let url = format!("{}/{}/day/{}/input", self.config.base_url, year, day);
+        let input = self.http.get(&url, self.session()?)?;
+
+        let dir = path.parent().expect("Inputs live in a directory");
+        fs::create_dir_all(dir).map_err(|e| ClientError::Io(dir.to_owned(), e))?;
+        fs::write(&path, &input).map_err(|e| ClientError::Io(path.clone(), e))?;
+
+        Ok(input)
+    }
+
+    pub fn submit(
+        &self,
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: &str,
+    ) -> Result<Verdict, ClientError> {
+        let url = format!("{}/{}/day/{}/answer", self.config.base_url, year, day);
+        let level = match part {
+            Part::One => "1",
+            Part::Two => "2",
+        };
+
+        let page = self.http.post(
+            &url,
+            self.session()?,
+            &[("level", level), ("answer", answer)],
+        )?;
+
+        parse_verdict(&page)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+
+    /// What the mock server saw of a request
+    #[derive(Debug, Clone, PartialEq)]
+    struct Request {
+        line: String,
+        cookie: Option<String>,
+        body: String,
+    }
+
+    /// A local HTTP server that answers each request with the next canned response and records what it was asked
+    struct MockServer {
+        url: String,
+        requests: Arc<Mutex<Vec<Request>>>,
+    }
+
+    impl MockServer {
+        fn start(responses: Vec<(u16, &'static str)>) -> MockServer {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let url = format!("http://{}", listener.local_addr().unwrap());
+            let requests = Arc::new(Mutex::new(Vec::new()));
+
+            let seen = Arc::clone(&requests);
+            thread::spawn(move || {
+                for (status, body) in responses {
+                    let (stream, _) = listener.accept().unwrap();
+                    let mut reader = BufReader::new(stream);
+
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+
+                    let mut cookie = None;
+                    let mut length = 0;
+                    loop {
+                        let mut header = String::new();
+                        reader.read_line(&mut header).unwrap();
+                        if header.trim().is_empty() {
+                            break;
+                        }
+
+                        let (name, value) = header.split_at(header.find(':').unwrap());
+                        let value = value[1..].trim().to_owned();
+                        match name.to_lowercase().as_str() {
+                            "cookie" => cookie = Some(value),
+                            "content-length" => length = value.parse().unwrap(),
+                            _ => (),
+                        }
+                    }
+
+                    let mut request_body = vec![0; length];
+                    reader.read_exact(&mut request_body).unwrap();
+
+                    seen.lock().unwrap().push(Request {
+                        line: line.trim().to_owned(),
+                        cookie,
+                        body: String::from_utf8(request_body).unwrap(),
+                    });
+
+                    write!(
+                        reader.get_mut(),
+                        "HTTP/1.1 {} Mock\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        status,
+                        body.len(),
+                        body
+                    )
+                    .unwrap();
+                }
+            });
+
+            MockServer { url, requests }
+        }
+
+        fn requests(&self) -> Vec<Request> {
+            self.requests.lock().unwrap().clone()
+        }
+    }
+
+    fn client(server: &MockServer, cache: &Path) -> Client<Ureq> {
+        Client::new(
+            Ureq::new(),
+            Config {
+                session: Some("abc123".to_owned()),
+                base_url: server.url.clone(),
+                cache_dir: cache.to_owned(),
+            },
+        )
+    }
+
+    #[test]
+    fn downloads_and_caches_input() {
+        let server = MockServer::start(vec![(200, "1721\n979\n")]);
+        let cache = tempfile::tempdir().unwrap();
+        let client = client(&server, cache.path());
+
+        assert_eq!(client.input(2020, 1).unwrap(), "1721\n979\n");
+        assert_eq!(client.input(2020, 1).unwrap(), "1721\n979\n");
+        assert_eq!(
+            fs::read_to_string(cache.path().join("2020").join("day1.txt")).unwrap(),
+            "1721\n979\n"
+        );
+
+        // The second call came from the cache
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].line, "GET /2020/day/1/input HTTP/1.1");
+        assert_eq!(requests[0].cookie.as_deref(), Some("session=abc123"));
+    }
+
+    #[test]
+    fn reports_errors() {
+        let server = MockServer::start(vec![(404, "Not found"), (500, "Broken")]);
+        let cache = tempfile::tempdir().unwrap();
+        let client = client(&server, cache.path());
+
+        assert!(matches!(
+            client.input(2020, 25),
+            Err(ClientError::Status(404, _))
+        ));
+        assert!(matches!(
+            client.submit(2020, 1, Part::One, "1"),
+            Err(ClientError::Status(500, _))
+        ));
+        assert!(!cache.path().join("2020").join("day25.txt").exists());
+
+        let offline = Client::new(Ureq::new(), Config::default());
+        assert!(matches!(
+            offline.input(2020, 1),
+            Err(ClientError::NoSession)
+        ));
+    }
+
+    #[test]
+    fn submits_answers() {
+        let server = MockServer::start(vec![
+            (
+                200,
+                "<article><p>That's the right answer! You are one gold star closer.</p></article>",
+            ),
+            (
+                200,
+                "<p>That's not the right answer; your answer is too high.</p>",
+            ),
+        ]);
+        let cache = tempfile::tempdir().unwrap();
+        let client = client(&server, cache.path());
+
+        assert_eq!(
+            client.submit(2020, 9, Part::Two, "123").unwrap(),
+            Verdict::Correct
+        );
+        assert_eq!(
+            client.submit(2020, 9, Part::One, "9 9").unwrap(),
+            Verdict::TooHigh
+        );
+
+        let requests = server.requests();
+        assert_eq!(requests[0].line, "POST /2020/day/9/answer HTTP/1.1");
+        assert_eq!(requests[0].body, "level=2&answer=123");
+        assert_eq!(requests[1].body, "level=1&answer=9+9");
+    }
+
+    #[test]
+    fn verdicts() {
+        assert_eq!(
+            parse_verdict("That's not the right answer; your answer is too low.").unwrap(),
+            Verdict::TooLow
+        );
+        assert_eq!(
+            parse_verdict("That's not the right answer. If you're stuck, ...").unwrap(),
+            Verdict::Incorrect
+        );
+        assert_eq!(
+            parse_verdict("You gave an answer too recently. You have 1m 5s left to wait.").unwrap(),
+            Verdict::Wait(Some(Duration::from_secs(65)))
+        );
+        assert_eq!(
+            parse_verdict("You gave an answer too recently. You have 38s left to wait.").unwrap(),
+            Verdict::Wait(Some(Duration::from_secs(38)))
+        );
+        assert_eq!(
+            parse_verdict(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            )
+            .unwrap(),
+            Verdict::WrongLevel
+        );
+        assert!(matches!(
+            parse_verdict("<html></html>"),
+            Err(ClientError::UnrecognizedResponse(_))
+        ));
+    }
+
+    #[test]
+    fn config() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("aoc.toml");
+
+        assert_eq!(
+            Config::load(&path).unwrap().base_url,
+            "https://adventofcode.com"
+        );
+
+        fs::write(&path, "session = \"abc123\"\ncache_dir = \"inputs\"\n").unwrap();
+        let config = Config::load(&path).unwrap();
+        assert_eq!(config.cache_dir, PathBuf::from("inputs"));
+        assert_eq!(config.base_url, "https://adventofcode.com");
+
+        fs::write(&path, "session = 5").unwrap();
+        assert!(matches!(
+            Config::load(&path),
+            Err(ClientError::Config(_, _))
+        ));
+    }
+}
@@ -0,0 +1,210 @@
+use std::error::Error;
+use std::fmt;
+
+/// An error produced while parsing a puzzle input. Carries enough context to point at the exact spot in the input file
+/// that couldn't be understood, rather than panicking somewhere deep inside the runner.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The puzzle day whose generator rejected the input (0 if unknown)
+    pub day: u8,
+    /// 1-based line number within the input (0 if unknown)
+    pub line: usize,
+    /// 1-based column within the line (0 if unknown)
+    pub column: usize,
+    /// The offending piece of input
+    pub text: String,
+    /// A human readable description of what went wrong
+    pub reason: String,
+}
+
+impl ParseError {
+    pub fn new<R: Into<String>, T: Into<String>>(reason: R, text: T) -> ParseError {
+        ParseError {
+            day: 0,
+            line: 0,
+            column: 0,
+            text: text.into(),
+            reason: reason.into(),
+        }
+    }
+
+    pub fn with_day(mut self, day: u8) -> ParseError {
+        self.day = day;
+        self
+    }
+
+    pub fn with_line(mut self, line: usize) -> ParseError {
+        self.line = line;
+        self
+    }
+
+    pub fn with_column(mut self, column: usize) -> ParseError {
+        self.column = column;
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut location = Vec::new();
+
+        if self.day != 0 {
+            location.push(format!("day {}", self.day));
+        }
+
+        if self.line != 0 {
+            location.push(format!("line {}", self.line));
+        }
+
+        if self.column != 0 {
+            location.push(format!("column {}", self.column));
+        }
+
+        if !location.is_empty() {
+            write!(f, "{}: ", location.join(", "))?;
+        }
+
+        write!(f, "{} {:?}", self.reason, self.text)
+    }
+}
+
+impl Error for ParseError {}
+
+/// Why a part couldn't produce an answer for an input that parsed fine
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    /// The solver used up its step budget without settling on an answer
+    DidNotConverge { steps: u64 },
+    /// The solver was still going when the runner's deadline passed
+    TimedOut { steps: u64 },
+    /// The input is well formed but doesn't have an answer
+    NoAnswer(String),
+    /// A parameter the runner set can't be used as the type the solver needs
+    InvalidParameter { name: String, reason: String },
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SolveError::DidNotConverge { steps } => {
+                write!(f, "Did not converge within {} steps", steps)
+            }
+            SolveError::TimedOut { steps } => write!(f, "Timed out after {} steps", steps),
+            SolveError::NoAnswer(reason) => write!(f, "No answer: {}", reason),
+            SolveError::InvalidParameter { name, reason } => {
+                write!(f, "Invalid parameter {}: {}", name, reason)
+            }
+        }
+    }
+}
+
+impl Error for SolveError {}
+
+/// Anything that can stop a day from producing an answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RunError {
+    Parse(ParseError),
+    Solve {
+        day: u8,
+        error: SolveError,
+    },
+    /// The solver panicked, which is a bug in the solver rather than in the input
+    Panicked {
+        day: u8,
+        message: String,
+    },
+}
+
+impl fmt::Display for RunError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RunError::Parse(e) => e.fmt(f),
+            RunError::Solve { day, error } => write!(f, "day {}: {}", day, error),
+            RunError::Panicked { day, message } => write!(f, "day {}: panicked: {}", day, message),
+        }
+    }
+}
+
+impl Error for RunError {}
+
+impl From<ParseError> for RunError {
+    fn from(e: ParseError) -> RunError {
+        RunError::Parse(e)
+    }
+}
+
+/// Parses each line of `input` with `parse`, tagging any error with the day and the line it occurred on.
+pub fn parse_lines<T, F>(day: u8, input: &str, parse: F) -> Result<Vec<T>, ParseError>
+where
+    F: Fn(&str) -> Result<T, ParseError>,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse(line).map_err(|e| e.with_day(day).with_line(i + 1)))
+        .collect()
+}
+
+/// Like `parse_lines`, but carries on past bad lines and returns an error for every one of them.
+pub fn check_lines<T, F>(day: u8, input: &str, parse: F) -> Vec<ParseError>
+where
+    F: Fn(&str) -> Result<T, ParseError>,
+{
+    input
+        .lines()
+        .enumerate()
+        .filter_map(|(i, line)| parse(line).err().map(|e| e.with_day(day).with_line(i + 1)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        let err = ParseError::new("Invalid operation", "foo")
+            .with_day(8)
+            .with_line(3)
+            .with_column(1);
+
+        assert_eq!(
+            err.to_string(),
+            "day 8, line 3, column 1: Invalid operation \"foo\""
+        );
+    }
+
+    #[test]
+    fn run_error_display() {
+        let err = RunError::Solve {
+            day: 11,
+            error: SolveError::DidNotConverge { steps: 100 },
+        };
+
+        assert_eq!(err.to_string(), "day 11: Did not converge within 100 steps");
+    }
+
+    #[test]
+    fn parse_lines_reports_line() {
+        let err = parse_lines(1, "1\n2\nthree\n4", |line| {
+            line.parse::<u32>()
+                .map_err(|_| ParseError::new("Unable to parse number", line))
+        })
+        .unwrap_err();
+
+        assert_eq!(err.day, 1);
+        assert_eq!(err.line, 3);
+        assert_eq!(err.text, "three");
+    }
+
+    #[test]
+    fn check_lines_reports_every_line() {
+        let errors = check_lines(1, "one\n2\nthree", |line| {
+            line.parse::<u32>()
+                .map_err(|_| ParseError::new("Unable to parse number", line))
+        });
+
+        let lines: Vec<usize> = errors.iter().map(|e| e.line).collect();
+        assert_eq!(lines, vec![1, 3]);
+    }
+}
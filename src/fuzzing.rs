@@ -0,0 +1,133 @@
+use std::str::FromStr;
+
+use crate::error::ParseError;
+use crate::grid::{Grid, Topology};
+use crate::years;
+use crate::years::y2020::{day07, day08, day12, day14};
+
+/// Runs both of a day's parsers over arbitrary input. Used by the fuzz targets in `fuzz/`, which expect it to never
+/// panic: bad input should only ever produce a `ParseError`, and `check` must report the same first problem that
+/// `parse` stops at.
+pub fn parsers(year: u16, day: u8, input: &str) {
+    let entry = years::get(year)
+        .and_then(|year| year.get(day))
+        .expect("Fuzz targets only exist for registered days");
+
+    let parsed = entry.parse(input);
+    let problems = entry.check(input);
+
+    assert_eq!(
+        parsed.err(),
+        problems.first().cloned(),
+        "parse and check disagree"
+    );
+}
+
+/// Parses every line as one of day 8's instructions. Like the rest of the single parser targets below, it only checks
+/// that bad lines come back as errors rather than panics.
+pub fn instructions(input: &str) {
+    for line in input.lines() {
+        let _ = day08::Instruction::from_str(line);
+    }
+}
+
+/// Parses every line as one of day 12's navigation actions
+pub fn actions(input: &str) {
+    for line in input.lines() {
+        let _ = day12::Action::from_str(line);
+    }
+}
+
+/// Parses every line as one of day 14's bitmasks, leaving off the `mask = ` of lines that have it so whole programs
+/// make good seeds
+pub fn bitmasks(input: &str) {
+    for line in input.lines() {
+        let _ = day14::Bitmask::from_str(line.trim_start_matches("mask = "));
+    }
+}
+
+/// Parses every line as one of day 7's bag rules, through its pest grammar
+pub fn bag_rules(input: &str) {
+    for line in input.lines() {
+        let _ = day07::parse_line(line);
+    }
+}
+
+/// Parses the input as a grid of the cells days 3 and 11 use, in every topology
+pub fn grids(input: &str) {
+    for &topology in &[Topology::Bounded, Topology::Wrapping, Topology::Sparse] {
+        let _ = Grid::parse(input, topology, |c| match c {
+            '.' | '#' | 'L' => Ok(c),
+            _ => Err(ParseError::new("Invalid cell", c.to_string())),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::path::Path;
+
+    fn seeds() -> std::path::PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("fuzz")
+            .join("seeds")
+    }
+
+    #[test]
+    fn examples() {
+        for (year, day, _, input) in years::examples() {
+            // Mangled versions of good input are the interesting cases
+            let inputs = [
+                input.clone(),
+                input[..input.len() / 2].to_owned(),
+                input.replace('\n', " \n"),
+                input.replace(|c: char| c.is_ascii_digit(), "x"),
+            ];
+
+            for input in &inputs {
+                parsers(year, day, input);
+
+                // Every day's examples are arbitrary input to the other days' parsers
+                instructions(input);
+                actions(input);
+                bitmasks(input);
+                bag_rules(input);
+                grids(input);
+            }
+        }
+    }
+
+    #[test]
+    fn seed_corpus_is_current() {
+        for (year, day, name, input) in years::examples() {
+            let path = seeds()
+                .join(year.to_string())
+                .join(format!("day{:02}", day))
+                .join(name);
+            let seed = fs::read_to_string(&path).unwrap_or_default();
+
+            assert_eq!(
+                seed,
+                input,
+                "{} is out of date, run `cargo test write_seed_corpus -- --ignored`",
+                path.display()
+            );
+        }
+    }
+
+    /// Copies every `aoc_test!` example into the fuzzing seed corpus.
+    #[test]
+    #[ignore]
+    fn write_seed_corpus() {
+        for (year, day, name, input) in years::examples() {
+            let dir = seeds()
+                .join(year.to_string())
+                .join(format!("day{:02}", day));
+
+            fs::create_dir_all(&dir).unwrap();
+            fs::write(dir.join(name), input).unwrap();
+        }
+    }
+}
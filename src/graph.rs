@@ -0,0 +1,305 @@
+use petgraph::algo;
+use petgraph::graph::NodeIndex;
+use petgraph::visit::EdgeRef;
+use petgraph::{Directed, Direction, Graph};
+use std::cell::RefCell;
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
+use std::hash::Hash;
+use std::rc::Rc;
+
+use crate::error::SolveError;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GraphError {
+    /// The graph goes round in circles through these nodes, in order, from the first back to itself
+    Cycle(Vec<String>),
+    /// Multiplying weights along the paths from this node overflowed a `u64`
+    Overflow(String),
+}
+
+impl fmt::Display for GraphError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GraphError::Cycle(labels) => {
+                write!(f, "Cycle through {} -> {}", labels.join(" -> "), labels[0])
+            }
+            GraphError::Overflow(label) => write!(f, "Weights overflow below {}", label),
+        }
+    }
+}
+
+impl std::error::Error for GraphError {}
+
+/// Graph puzzles are only asked about graphs that make sense, so one that doesn't has no answer
+impl From<GraphError> for SolveError {
+    fn from(e: GraphError) -> SolveError {
+        SolveError::NoAnswer(e.to_string())
+    }
+}
+
+/// A directed graph whose nodes are looked up by label, such as day 7's bags by colour, with edges weighted by how
+/// many of one node the other holds. Ancestors and descendants are worked out once per node, and path products once
+/// for the whole graph, then remembered until the graph changes.
+#[derive(Debug, Clone)]
+pub struct LabeledGraph<L, W> {
+    graph: Graph<L, W, Directed>,
+    indices: HashMap<L, NodeIndex>,
+    ancestors: RefCell<HashMap<NodeIndex, Rc<HashSet<NodeIndex>>>>,
+    descendants: RefCell<HashMap<NodeIndex, Rc<HashSet<NodeIndex>>>>,
+    products: RefCell<HashMap<NodeIndex, Result<u64, GraphError>>>,
+}
+
+impl<L, W> Default for LabeledGraph<L, W> {
+    fn default() -> LabeledGraph<L, W> {
+        LabeledGraph {
+            graph: Graph::new(),
+            indices: HashMap::new(),
+            ancestors: RefCell::default(),
+            descendants: RefCell::default(),
+            products: RefCell::default(),
+        }
+    }
+}
+
+impl<L: Clone + Eq + Hash + fmt::Display, W: Copy + Into<u64>> LabeledGraph<L, W> {
+    pub fn new() -> LabeledGraph<L, W> {
+        LabeledGraph::default()
+    }
+
+    fn forget(&mut self) {
+        self.ancestors.get_mut().clear();
+        self.descendants.get_mut().clear();
+        self.products.get_mut().clear();
+    }
+
+    /// The node labelled `label`, adding it if there isn't one yet
+    pub fn add_node(&mut self, label: L) -> NodeIndex {
+        if let Some(&node) = self.indices.get(&label) {
+            return node;
+        }
+
+        self.forget();
+        let node = self.graph.add_node(label.clone());
+        self.indices.insert(label, node);
+        node
+    }
+
+    /// Adds an edge from `from` to `to`, adding either node if it isn't in the graph yet
+    pub fn add_edge(&mut self, from: L, to: L, weight: W) {
+        let from = self.add_node(from);
+        let to = self.add_node(to);
+
+        self.forget();
+        self.graph.add_edge(from, to, weight);
+    }
+
+    pub fn index(&self, label: &L) -> Option<NodeIndex> {
+        self.indices.get(label).copied()
+    }
+
+    pub fn label(&self, node: NodeIndex) -> &L {
+        &self.graph[node]
+    }
+
+    /// The underlying graph, for anything petgraph can do that this can't
+    pub fn graph(&self) -> &Graph<L, W, Directed> {
+        &self.graph
+    }
+
+    /// Every node reachable by following edges in `direction` from `node`, not counting `node` unless it's on a cycle
+    fn reachable(&self, node: NodeIndex, direction: Direction) -> HashSet<NodeIndex> {
+        let mut reached = HashSet::new();
+        let mut queue: VecDeque<NodeIndex> =
+            self.graph.neighbors_directed(node, direction).collect();
+
+        while let Some(next) = queue.pop_front() {
+            if reached.insert(next) {
+                queue.extend(self.graph.neighbors_directed(next, direction));
+            }
+        }
+
+        reached
+    }
+
+    fn memoized(
+        &self,
+        memo: &RefCell<HashMap<NodeIndex, Rc<HashSet<NodeIndex>>>>,
+        node: NodeIndex,
+        direction: Direction,
+    ) -> Rc<HashSet<NodeIndex>> {
+        if let Some(nodes) = memo.borrow().get(&node) {
+            return Rc::clone(nodes);
+        }
+
+        let nodes = Rc::new(self.reachable(node, direction));
+        memo.borrow_mut().insert(node, Rc::clone(&nodes));
+        nodes
+    }
+
+    /// Every node with a path to `node`
+    pub fn ancestors(&self, node: NodeIndex) -> Rc<HashSet<NodeIndex>> {
+        self.memoized(&self.ancestors, node, Direction::Incoming)
+    }
+
+    /// Every node `node` has a path to
+    pub fn descendants(&self, node: NodeIndex) -> Rc<HashSet<NodeIndex>> {
+        self.memoized(&self.descendants, node, Direction::Outgoing)
+    }
+
+    /// The sum over every path leaving `node` of the product of the weights along it, which for nested containers is
+    /// how many things are inside `node` altogether. Fails if the graph has a cycle, as the sum could never end.
+    pub fn path_products(&self, node: NodeIndex) -> Result<u64, GraphError> {
+        if self.products.borrow().is_empty() {
+            let products = self.all_path_products()?;
+            *self.products.borrow_mut() = products;
+        }
+
+        self.products.borrow()[&node].clone()
+    }
+
+    /// Every node's path products, working up from the bottom of the graph so that each node's children are done
+    /// before it. A node overflows if anything below it does.
+    fn all_path_products(&self) -> Result<HashMap<NodeIndex, Result<u64, GraphError>>, GraphError> {
+        let mut products: HashMap<NodeIndex, Result<u64, GraphError>> = HashMap::new();
+
+        for node in self.toposort()?.into_iter().rev() {
+            let total = self.graph.edges(node).try_fold(0u64, |total, edge| {
+                // Each of the `weight` children counts itself and everything below it
+                let below = products[&edge.target()].clone()?;
+
+                below
+                    .checked_add(1)
+                    .and_then(|child| child.checked_mul((*edge.weight()).into()))
+                    .and_then(|children| total.checked_add(children))
+                    .ok_or_else(|| GraphError::Overflow(self.graph[node].to_string()))
+            });
+
+            products.insert(node, total);
+        }
+
+        Ok(products)
+    }
+
+    /// Every node, each before any node it has an edge to
+    pub fn toposort(&self) -> Result<Vec<NodeIndex>, GraphError> {
+        algo::toposort(&self.graph, None)
+            .map_err(|cycle| GraphError::Cycle(self.cycle(cycle.node_id())))
+    }
+
+    /// The labels along a shortest cycle from `start` back to itself
+    fn cycle(&self, start: NodeIndex) -> Vec<String> {
+        let mut parents = HashMap::new();
+        let mut queue = VecDeque::from(vec![start]);
+
+        while let Some(node) = queue.pop_front() {
+            for next in self.graph.neighbors(node) {
+                if next == start {
+                    let mut path = vec![node];
+                    while let Some(&parent) = parents.get(path.last().unwrap()) {
+                        path.push(parent);
+                    }
+
+                    return path
+                        .iter()
+                        .rev()
+                        .map(|&node| self.graph[node].to_string())
+                        .collect();
+                }
+
+                if let Entry::Vacant(entry) = parents.entry(next) {
+                    entry.insert(node);
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        vec![self.graph[start].to_string()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labels(graph: &LabeledGraph<&str, u8>, nodes: &HashSet<NodeIndex>) -> Vec<String> {
+        let mut labels: Vec<String> = nodes
+            .iter()
+            .map(|&node| graph.label(node).to_string())
+            .collect();
+        labels.sort();
+        labels
+    }
+
+    /// A box holding two bags of three marbles each, plus one loose marble
+    fn boxes() -> LabeledGraph<&'static str, u8> {
+        let mut graph = LabeledGraph::new();
+
+        graph.add_edge("box", "bag", 2);
+        graph.add_edge("bag", "marble", 3);
+        graph.add_edge("box", "marble", 1);
+        graph.add_node("table");
+        graph
+    }
+
+    #[test]
+    fn queries() {
+        let graph = boxes();
+        let node = |label| graph.index(&label).unwrap();
+
+        assert_eq!(
+            labels(&graph, &graph.ancestors(node("marble"))),
+            vec!["bag", "box"]
+        );
+        assert_eq!(
+            labels(&graph, &graph.descendants(node("box"))),
+            vec!["bag", "marble"]
+        );
+        assert!(graph.ancestors(node("table")).is_empty());
+        assert_eq!(graph.index(&"chair"), None);
+
+        assert_eq!(graph.path_products(node("box")), Ok(2 + 2 * 3 + 1));
+        assert_eq!(graph.path_products(node("marble")), Ok(0));
+
+        let order = graph.toposort().unwrap();
+        let position = |label| order.iter().position(|&n| n == node(label));
+        assert!(position("box") < position("bag"));
+        assert!(position("bag") < position("marble"));
+    }
+
+    #[test]
+    fn deep_chain() {
+        let mut graph = LabeledGraph::new();
+        for level in 0..100_000u32 {
+            graph.add_edge(level, level + 1, 1u8);
+        }
+
+        let top = graph.index(&0).unwrap();
+        assert_eq!(graph.path_products(top), Ok(100_000));
+    }
+
+    #[test]
+    fn cycles_and_overflow() {
+        let mut graph = boxes();
+        graph.add_edge("marble", "box", 1);
+        let bag = graph.index(&"bag").unwrap();
+
+        // The box holds a marble directly, so that's the shortest way round
+        let cycle = GraphError::Cycle(vec!["marble".to_owned(), "box".to_owned()]);
+        assert_eq!(graph.path_products(bag), Err(cycle.clone()));
+        assert_eq!(graph.toposort(), Err(cycle.clone()));
+        assert_eq!(cycle.to_string(), "Cycle through marble -> box -> marble");
+
+        let mut graph = LabeledGraph::new();
+        for level in 0..20u8 {
+            graph.add_edge(level, level + 1, 255u8);
+        }
+        let top = graph.index(&0).unwrap();
+        assert!(matches!(
+            graph.path_products(top),
+            Err(GraphError::Overflow(_))
+        ));
+        assert_eq!(graph.path_products(graph.index(&19).unwrap()), Ok(255));
+    }
+}
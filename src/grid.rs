@@ -0,0 +1,443 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::error::ParseError;
+use crate::vec2::{Heading, Vec2};
+
+/// A position on a grid, with x counting columns to the right and y counting rows down
+pub type Point = Vec2;
+
+/// The steps to the four cells sharing an edge with a cell
+pub const ORTHOGONAL: [Point; 4] = [
+    Heading::North.step(),
+    Heading::West.step(),
+    Heading::East.step(),
+    Heading::South.step(),
+];
+
+/// The steps to the eight cells sharing an edge or a corner with a cell
+pub const ADJACENT: [Point; 8] = [
+    Point::new(-1, -1),
+    Point::new(0, -1),
+    Point::new(1, -1),
+    Point::new(-1, 0),
+    Point::new(1, 0),
+    Point::new(-1, 1),
+    Point::new(0, 1),
+    Point::new(1, 1),
+];
+
+/// What lies beyond a grid's edges
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Topology {
+    /// Nothing: points outside the grid have no cell
+    Bounded,
+    /// The grid again: points outside wrap around to the opposite edge, in both directions
+    Wrapping,
+    /// More grid: only cells that have been set exist, anywhere on the plane, and the grid's size is whatever covers
+    /// them
+    Sparse,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Cells<T> {
+    Dense(Vec<T>),
+    Sparse(BTreeMap<Point, T>),
+}
+
+/// A two dimensional grid of cells. Bounded and wrapping grids hold a cell at every point from `(0, 0)` up to their
+/// width and height, while sparse grids only hold the cells that have been set.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Grid<T> {
+    topology: Topology,
+    width: usize,
+    height: usize,
+    cells: Cells<T>,
+}
+
+impl<T> Grid<T> {
+    /// A grid with every cell set to `value`. Sparse grids start out empty instead.
+    pub fn filled(width: usize, height: usize, value: T, topology: Topology) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = match topology {
+            Topology::Sparse => Cells::Sparse(BTreeMap::new()),
+            _ => Cells::Dense(vec![value; width * height]),
+        };
+
+        Grid {
+            topology,
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses a grid with one row per line, turning each character into a cell with `cell`. Errors point at the line
+    /// and column they were found at. Every row has to be as wide as the first, and there has to be at least one.
+    pub fn parse<F>(input: &str, topology: Topology, mut cell: F) -> Result<Grid<T>, ParseError>
+    where
+        F: FnMut(char) -> Result<T, ParseError>,
+    {
+        let width = input
+            .lines()
+            .next()
+            .ok_or_else(|| ParseError::new("Missing first row", ""))?
+            .chars()
+            .count();
+
+        let mut cells = Vec::new();
+        for (y, row) in input.lines().enumerate() {
+            if row.chars().count() != width {
+                return Err(
+                    ParseError::new("Row width doesn't match first row", row).with_line(y + 1)
+                );
+            }
+
+            for (x, c) in row.chars().enumerate() {
+                cells.push(cell(c).map_err(|e| e.with_line(y + 1).with_column(x + 1))?);
+            }
+        }
+
+        let height = cells.len() / width.max(1);
+        let cells = match topology {
+            Topology::Sparse => Cells::Sparse(
+                cells
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, cell)| (Point::new((i % width) as i64, (i / width) as i64), cell))
+                    .collect(),
+            ),
+            _ => Cells::Dense(cells),
+        };
+
+        Ok(Grid {
+            topology,
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn topology(&self) -> Topology {
+        self.topology
+    }
+
+    /// How many columns the grid has. For sparse grids, how many columns its leftmost and rightmost cells span.
+    pub fn width(&self) -> usize {
+        match &self.cells {
+            Cells::Dense(_) => self.width,
+            Cells::Sparse(_) => self
+                .bounds()
+                .map_or(0, |(min, max)| (max.x - min.x + 1) as usize),
+        }
+    }
+
+    /// How many rows the grid has. For sparse grids, how many rows its top and bottom cells span.
+    pub fn height(&self) -> usize {
+        match &self.cells {
+            Cells::Dense(_) => self.height,
+            Cells::Sparse(_) => self
+                .bounds()
+                .map_or(0, |(min, max)| (max.y - min.y + 1) as usize),
+        }
+    }
+
+    /// The top left and bottom right corners of the smallest rectangle covering every cell, if there are any
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        match &self.cells {
+            Cells::Dense(_) if self.width == 0 || self.height == 0 => None,
+            Cells::Dense(_) => Some((
+                Point::new(0, 0),
+                Point::new(self.width as i64 - 1, self.height as i64 - 1),
+            )),
+            Cells::Sparse(cells) => cells.keys().fold(None, |bounds, &point| {
+                let (min, max) = bounds.unwrap_or((point, point));
+
+                Some((
+                    Point::new(min.x.min(point.x), min.y.min(point.y)),
+                    Point::new(max.x.max(point.x), max.y.max(point.y)),
+                ))
+            }),
+        }
+    }
+
+    /// Where `point`'s cell lives in a dense grid, after wrapping if the grid wraps
+    fn index(&self, point: Point) -> Option<usize> {
+        let (width, height) = (self.width as i64, self.height as i64);
+
+        let point = match self.topology {
+            Topology::Wrapping if width > 0 && height > 0 => {
+                Point::new(point.x.rem_euclid(width), point.y.rem_euclid(height))
+            }
+            _ => point,
+        };
+
+        if (0..width).contains(&point.x) && (0..height).contains(&point.y) {
+            Some((point.x + point.y * width) as usize)
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        match &self.cells {
+            Cells::Dense(cells) => self.index(point).map(|i| &cells[i]),
+            Cells::Sparse(cells) => cells.get(&point),
+        }
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        let index = self.index(point);
+
+        match &mut self.cells {
+            Cells::Dense(cells) => index.map(move |i| &mut cells[i]),
+            Cells::Sparse(cells) => cells.get_mut(&point),
+        }
+    }
+
+    /// Sets `point`'s cell, returning false if it lies outside a bounded grid
+    pub fn set(&mut self, point: Point, value: T) -> bool {
+        if let Cells::Sparse(cells) = &mut self.cells {
+            cells.insert(point, value);
+            return true;
+        }
+
+        match self.get_mut(point) {
+            Some(cell) => {
+                *cell = value;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Every cell and its point, row by row
+    pub fn iter(&self) -> Box<dyn Iterator<Item = (Point, &T)> + '_> {
+        match &self.cells {
+            Cells::Dense(cells) => {
+                let width = self.width.max(1);
+
+                Box::new(cells.iter().enumerate().map(move |(i, cell)| {
+                    (Point::new((i % width) as i64, (i / width) as i64), cell)
+                }))
+            }
+            Cells::Sparse(cells) => Box::new(cells.iter().map(|(&point, cell)| (point, cell))),
+        }
+    }
+
+    /// How many cells match `predicate`
+    pub fn count<P: Fn(&T) -> bool>(&self, predicate: P) -> usize {
+        self.iter().filter(|(_, cell)| predicate(cell)).count()
+    }
+
+    /// A grid of the same shape with every cell replaced by `f` of its point and value
+    pub fn map<U, F: FnMut(Point, &T) -> U>(&self, mut f: F) -> Grid<U> {
+        let cells = match &self.cells {
+            Cells::Dense(_) => {
+                Cells::Dense(self.iter().map(|(point, cell)| f(point, cell)).collect())
+            }
+            Cells::Sparse(_) => Cells::Sparse(
+                self.iter()
+                    .map(|(point, cell)| (point, f(point, cell)))
+                    .collect(),
+            ),
+        };
+
+        Grid {
+            topology: self.topology,
+            width: self.width,
+            height: self.height,
+            cells,
+        }
+    }
+
+    /// The cells a step in each of `steps` away from `point`, along with where they are
+    fn around<'a>(
+        &'a self,
+        point: Point,
+        steps: &'a [Point],
+    ) -> impl Iterator<Item = (Point, &'a T)> + 'a {
+        steps.iter().filter_map(move |&step| {
+            let next = point + step;
+            self.get(next).map(|cell| (next, cell))
+        })
+    }
+
+    /// The up to four cells sharing an edge with `point`'s
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = (Point, &T)> + '_ {
+        self.around(point, &ORTHOGONAL)
+    }
+
+    /// The up to eight cells sharing an edge or a corner with `point`'s
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = (Point, &T)> + '_ {
+        self.around(point, &ADJACENT)
+    }
+
+    /// The cells passed going from `point` in steps of `step`, not counting `point`'s own. Stops on heading away from
+    /// the edge of a bounded grid or of a sparse grid's cells, or on getting back to `point` in a wrapping grid. Rays
+    /// from outside the edge carry on until they've crossed the grid.
+    pub fn ray(&self, point: Point, step: Point) -> impl Iterator<Item = (Point, &T)> + '_ {
+        let bounds = self.bounds();
+        let mut next = point;
+
+        std::iter::from_fn(move || loop {
+            next += step;
+
+            let (min, max) = bounds?;
+            let leaving = (next.x < min.x && step.x <= 0)
+                || (next.y < min.y && step.y <= 0)
+                || (next.x > max.x && step.x >= 0)
+                || (next.y > max.y && step.y >= 0);
+
+            match self.topology {
+                _ if step == Point::ZERO => return None,
+                Topology::Wrapping if self.index(next) == self.index(point) => return None,
+                Topology::Bounded | Topology::Sparse if leaving => return None,
+                _ => (),
+            }
+
+            // Sparse grids can have gaps, which rays pass straight over
+            if let Some(cell) = self.get(next) {
+                return Some((next, cell));
+            }
+        })
+    }
+
+    /// The first cell matching `visible` in each of the eight directions from `point`, for puzzles where things can
+    /// see past cells that don't block their view
+    pub fn visible<'a, P>(
+        &'a self,
+        point: Point,
+        visible: P,
+    ) -> impl Iterator<Item = (Point, &'a T)> + 'a
+    where
+        P: Fn(&T) -> bool + Copy + 'a,
+    {
+        ADJACENT
+            .iter()
+            .filter_map(move |&step| self.ray(point, step).find(|(_, cell)| visible(cell)))
+    }
+}
+
+/// Prints the grid row by row. Sparse grids print the rectangle covering their cells, with gaps left blank.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (min, max) = match self.bounds() {
+            Some(bounds) => bounds,
+            None => return Ok(()),
+        };
+
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                match self.get(Point::new(x, y)) {
+                    Some(cell) => write!(f, "{}", cell)?,
+                    None => write!(f, " ")?,
+                }
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(topology: Topology) -> Grid<u32> {
+        Grid::parse("123\n456\n789", topology, |c| {
+            c.to_digit(10)
+                .ok_or_else(|| ParseError::new("Not a digit", c.to_string()))
+        })
+        .unwrap()
+    }
+
+    fn values<'a>(cells: impl Iterator<Item = (Point, &'a u32)>) -> Vec<u32> {
+        cells.map(|(_, &cell)| cell).collect()
+    }
+
+    #[test]
+    fn parse_and_display() {
+        let grid = digits(Topology::Bounded);
+
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert_eq!(grid.get(Point::new(2, 1)), Some(&6));
+        assert_eq!(grid.get(Point::new(3, 1)), None);
+        assert_eq!(grid.to_string(), "123\n456\n789\n");
+
+        let error = Grid::parse("12\n3x", Topology::Bounded, |c| {
+            c.to_digit(10)
+                .ok_or_else(|| ParseError::new("Not a digit", c.to_string()))
+        })
+        .unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+
+        let error = Grid::parse("12\n3", Topology::Bounded, Ok::<char, ParseError>).unwrap_err();
+        assert_eq!(error.reason, "Row width doesn't match first row");
+        assert!(Grid::parse("", Topology::Bounded, Ok::<char, ParseError>).is_err());
+    }
+
+    #[test]
+    fn neighbours() {
+        let bounded = digits(Topology::Bounded);
+        let corner = Point::new(0, 0);
+
+        assert_eq!(values(bounded.neighbours4(corner)), vec![2, 4]);
+        assert_eq!(values(bounded.neighbours8(corner)), vec![2, 4, 5]);
+        assert_eq!(values(bounded.neighbours8(Point::new(1, 1))).len(), 8);
+
+        let wrapping = digits(Topology::Wrapping);
+        assert_eq!(values(wrapping.neighbours4(corner)), vec![7, 3, 2, 4]);
+        assert_eq!(wrapping.get(Point::new(-1, 5)), Some(&9));
+    }
+
+    #[test]
+    fn rays() {
+        let bounded = digits(Topology::Bounded);
+        assert_eq!(
+            values(bounded.ray(Point::new(0, 0), Point::new(1, 1))),
+            vec![5, 9]
+        );
+        assert_eq!(bounded.ray(Point::new(0, 0), Point::new(-1, 0)).count(), 0);
+
+        let wrapping = digits(Topology::Wrapping);
+        assert_eq!(
+            values(wrapping.ray(Point::new(0, 0), Point::new(1, 0))),
+            vec![2, 3]
+        );
+        assert_eq!(
+            values(wrapping.ray(Point::new(1, 1), Heading::North.step())),
+            vec![2, 8]
+        );
+
+        // Seeing past the small numbers in the way, and nothing at all to the right
+        let big = values(bounded.visible(Point::new(0, 0), |&cell| cell > 4));
+        assert_eq!(big, vec![7, 5]);
+    }
+
+    #[test]
+    fn sparse() {
+        let mut grid = Grid::filled(0, 0, '.', Topology::Sparse);
+        assert_eq!(grid.to_string(), "");
+
+        grid.set(Point::new(-2, 0), '#');
+        grid.set(Point::new(1, 1), '#');
+        assert_eq!((grid.width(), grid.height()), (4, 2));
+        assert_eq!(grid.get(Point::new(0, 0)), None);
+        assert_eq!(grid.to_string(), "#   \n   #\n");
+
+        let rays: Vec<char> = grid
+            .ray(Point::new(-5, -3), Point::new(1, 1))
+            .map(|(_, &c)| c)
+            .collect();
+        assert_eq!(rays, vec!['#']);
+        assert_eq!(grid.ray(Point::new(-2, 0), Point::new(1, 0)).count(), 0);
+        assert_eq!(grid.ray(Point::new(-5, 0), Point::new(-1, 0)).count(), 0);
+        assert_eq!(grid.neighbours8(Point::new(0, 0)).count(), 1);
+        assert_eq!(grid.count(|&c| c == '#'), 2);
+    }
+}
@@ -0,0 +1,117 @@
+use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
+
+/// Where puzzle inputs are read from
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputSource {
+    /// A single input file, only usable when running one day
+    File(PathBuf),
+    /// Standard input, only usable when running one day
+    Stdin,
+    /// A directory of `dayN.txt` files, the same layout cargo-aoc uses
+    Dir(PathBuf),
+}
+
+impl InputSource {
+    pub fn read(&self, day: u8) -> io::Result<String> {
+        match self {
+            InputSource::File(path) => fs::read_to_string(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+            InputSource::Dir(dir) => fs::read_to_string(dir.join(format!("day{}.txt", day))),
+        }
+    }
+
+    /// The file a day's input is read from, if it comes from a file at all
+    pub fn path(&self, day: u8) -> Option<PathBuf> {
+        match self {
+            InputSource::File(path) => Some(path.clone()),
+            InputSource::Stdin => None,
+            InputSource::Dir(dir) => Some(dir.join(format!("day{}.txt", day))),
+        }
+    }
+}
+
+/// Smooths over the differences in how an input file can end up saved: strips a byte order mark, CRLF line endings,
+/// trailing whitespace on each line and trailing blank lines. Every generator runs its input through this first.
+pub fn normalize(input: &str) -> String {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let lines: Vec<&str> = input.lines().map(str::trim_end).collect();
+
+    let end = lines
+        .iter()
+        .rposition(|line| !line.is_empty())
+        .map_or(0, |last| last + 1);
+
+    lines[..end].join("\n")
+}
+
+/// A run of consecutive non-blank lines
+#[derive(Debug, Clone, PartialEq)]
+pub struct Block<'a> {
+    /// 1-based line number of the block's first line within the whole input
+    pub line: usize,
+    pub lines: Vec<&'a str>,
+}
+
+impl<'a> Block<'a> {
+    /// Each line of the block along with its 1-based line number within the whole input
+    pub fn numbered_lines(&self) -> impl Iterator<Item = (usize, &'a str)> + '_ {
+        self.lines
+            .iter()
+            .enumerate()
+            .map(move |(i, line)| (self.line + i, *line))
+    }
+}
+
+/// Splits a normalized input into blocks separated by one or more blank lines.
+pub fn blocks(input: &str) -> Vec<Block<'_>> {
+    let mut blocks = Vec::new();
+    let mut current: Option<Block> = None;
+
+    for (i, line) in input.lines().enumerate() {
+        if line.is_empty() {
+            blocks.extend(current.take());
+            continue;
+        }
+
+        current
+            .get_or_insert_with(|| Block {
+                line: i + 1,
+                lines: Vec::new(),
+            })
+            .lines
+            .push(line);
+    }
+
+    blocks.extend(current);
+    blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_endings() {
+        assert_eq!(normalize("\u{feff}1\r\n2 \r\n\r\n\n"), "1\n2");
+        assert_eq!(normalize("\na\n\nb"), "\na\n\nb");
+        assert_eq!(normalize("\n\n"), "");
+    }
+
+    #[test]
+    fn split_blocks() {
+        let blocks = blocks("a\nb\n\n\nc\n");
+
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[0].lines, vec!["a", "b"]);
+        assert_eq!(
+            blocks[1].numbered_lines().collect::<Vec<_>>(),
+            vec![(5, "c")]
+        );
+    }
+}
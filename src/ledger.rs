@@ -0,0 +1,175 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::answer::Answer;
+use crate::solution::Part;
+use crate::years;
+
+/// The ledger checked into the repository root
+pub const DEFAULT_PATH: &str = "answers.json";
+
+/// A verified answer for one part of one day, along with the input file that produced it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Record {
+    /// Ledgers from before solutions were grouped by year only ever held 2020's answers
+    #[serde(default = "default_year")]
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub input: PathBuf,
+    pub answer: Answer,
+}
+
+fn default_year() -> u16 {
+    2020
+}
+
+/// A record whose solver no longer produces the answer it was verified with
+#[derive(Debug, PartialEq)]
+pub struct Drift {
+    pub record: Record,
+    /// What the solver produces now, or why it couldn't produce anything
+    pub actual: Result<Answer, String>,
+}
+
+/// Answers that have been verified against the real puzzle inputs. Re-running every solver against the ledger catches
+/// refactors that silently change results.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Ledger {
+    records: Vec<Record>,
+}
+
+impl Ledger {
+    /// Loads a ledger, treating a missing file as an empty ledger.
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Ledger> {
+        match fs::read_to_string(path) {
+            Ok(json) => serde_json::from_str(&json)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Ledger::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let json = serde_json::to_string_pretty(self).expect("Ledgers are always serializable");
+
+        fs::write(path, json + "\n")
+    }
+
+    pub fn records(&self) -> &[Record] {
+        &self.records
+    }
+
+    /// Adds a verified answer, replacing any previous answer for the same year, day, part and input.
+    pub fn record(&mut self, record: Record) {
+        match self.records.iter_mut().find(|r| {
+            (r.year, r.day, r.part, &r.input)
+                == (record.year, record.day, record.part, &record.input)
+        }) {
+            Some(existing) => *existing = record,
+            None => self.records.push(record),
+        }
+
+        self.records.sort_by(|a, b| {
+            (a.year, a.day, a.part, &a.input).cmp(&(b.year, b.day, b.part, &b.input))
+        });
+    }
+
+    /// Re-runs every recorded solver against its stored input, returning every record that no longer matches.
+    pub fn verify(&self) -> Vec<Drift> {
+        self.records
+            .iter()
+            .filter_map(|record| {
+                let actual = solve(record.year, record.day, record.part, &record.input);
+
+                if actual.as_ref() == Ok(&record.answer) {
+                    None
+                } else {
+                    Some(Drift {
+                        record: record.clone(),
+                        actual,
+                    })
+                }
+            })
+            .collect()
+    }
+}
+
+/// Runs a single part of a single day of `year` against an input file. A solver that panics fails with its panic
+/// message, so verifying carries on with the other records.
+pub fn solve(year: u16, day: u8, part: u8, input: &Path) -> Result<Answer, String> {
+    let entry = years::get(year)
+        .and_then(|entry| entry.get(day))
+        .ok_or_else(|| format!("Day {} of {} isn't implemented", day, year))?;
+    let part = match part {
+        1 => Part::One,
+        2 => Part::Two,
+        _ => return Err(format!("Invalid part {}", part)),
+    };
+
+    let input = fs::read_to_string(input)
+        .map_err(|e| format!("Unable to read {}: {}", input.display(), e))?;
+
+    entry.try_run(&input, part).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(part: u8, input: &Path, answer: &str) -> Record {
+        Record {
+            year: 2020,
+            day: 1,
+            part,
+            input: input.to_owned(),
+            answer: Answer::from(answer),
+        }
+    }
+
+    #[test]
+    fn verify() {
+        let dir = tempfile::tempdir().unwrap();
+        let input = dir.path().join("day1.txt");
+        fs::write(&input, "1721\n979\n366\n299\n675\n1456\n").unwrap();
+
+        let mut ledger = Ledger::default();
+        ledger.record(record(1, &input, "514579"));
+        ledger.record(record(2, &input, "241861950"));
+        assert!(ledger.verify().is_empty());
+
+        // Re-recording replaces the old answer rather than adding a second one
+        ledger.record(record(2, &input, "42"));
+        assert_eq!(ledger.records().len(), 2);
+
+        let drift = ledger.verify();
+        assert_eq!(drift.len(), 1);
+        assert_eq!(drift[0].actual, Ok(Answer::Int(241861950)));
+    }
+
+    #[test]
+    fn round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("answers.json");
+
+        assert_eq!(Ledger::load(&path).unwrap(), Ledger::default());
+
+        let mut ledger = Ledger::default();
+        ledger.record(record(1, Path::new("input/2020/day1.txt"), "514579"));
+        ledger.save(&path).unwrap();
+
+        assert_eq!(Ledger::load(&path).unwrap(), ledger);
+    }
+
+    #[test]
+    fn defaults_to_2020() {
+        let json = r#"[{"day": 1, "part": 1, "input": "input/2020/day1.txt", "answer": "514579"}]"#;
+        let ledger: Ledger = serde_json::from_str(json).unwrap();
+
+        assert_eq!(ledger.records()[0].year, 2020);
+        assert_eq!(ledger.records()[0].answer, Answer::Int(514579));
+    }
+}
@@ -0,0 +1,36 @@
+#[macro_use]
+extern crate lazy_static;
+#[allow(unused_imports)]
+#[macro_use]
+extern crate aoc_runner_derive;
+extern crate pest;
+#[macro_use]
+extern crate pest_derive;
+
+pub mod answer;
+pub mod bench;
+pub mod budget;
+pub mod cli;
+pub mod client;
+pub mod error;
+pub mod fuzzing;
+pub mod graph;
+pub mod grid;
+pub mod input;
+pub mod ledger;
+mod macros;
+pub mod math;
+pub mod parallel;
+pub mod params;
+pub mod scaffold;
+pub mod solution;
+pub mod synth;
+pub mod trace;
+pub mod vec2;
+pub mod vm;
+pub mod watch;
+pub mod years;
+
+use aoc_runner_derive::aoc_lib;
+
+aoc_lib! { year = 2020 }
@@ -1,33 +1,197 @@
+#[cfg(test)]
+use crate::answer::Answer;
+#[cfg(test)]
+use std::fmt::Display;
+#[cfg(test)]
+use std::fs;
+#[cfg(test)]
+use std::path::Path;
+
+/// Tests each part against the answers expected for example inputs. Expected answers are compared as `Answer`s, so they
+/// can be written as whichever literal reads best, e.g. `part1 = 514579;` or `part1 = "514579";`.
 #[macro_export]
 macro_rules! aoc_test {
+  // Several named examples, each with its own input and the answers expected for it. Inputs are either given inline
+  // with `input = "..."` or loaded from `tests/fixtures/YYYY/dayNN/` with `file = "name.txt"`. Examples that change
+  // the puzzle's parameters list them after the name, e.g. `example small(preamble = 5) { ... }`.
+  {
+    $(
+      example $name:ident $(($($param:ident = $value:expr),* $(,)?))? {
+        $kind:ident = $source:literal;
+        $($test_part:ident = $test_output:literal);* $(;)?
+      }
+    )+
+  } => {
+    #[cfg(test)]
+    pub(crate) mod tests {
+        /// Every example's input by name, so other tests can reuse them
+        pub(crate) fn examples() -> Vec<(&'static str, String)> {
+            vec![$((stringify!($name), $name::raw_input())),+]
+        }
+
+        $(
+          mod $name {
+            use super::super::*;
+
+            pub(super) fn raw_input() -> String {
+                $crate::aoc_test!(@input $kind $source)
+            }
+
+            fn params() -> $crate::params::Params {
+                #[allow(unused_mut)]
+                let mut params = $crate::params::Params::default();
+                $($(
+                  params.insert($crate::macros::module_day(module_path!()), stringify!($param), $value);
+                )*)?
+
+                params
+            }
+
+            $(
+              #[test]
+              fn $test_part() {
+                $crate::params::set_params(params());
+                let input = generator(&raw_input()).expect("Unable to parse example input");
+
+                let solution = super::super::$test_part(&input);
+                let output = $crate::macros::TestOutput::render(solution);
+
+                assert_eq!(output, $crate::answer::Answer::from($test_output));
+              }
+            )*
+          }
+        )+
+    }
+  };
+
+  // A single inline example shared by every part
   {
     input = $input:literal;
     $($test_part:ident = $test_output:literal);* $(;)?
   } => {
     #[cfg(test)]
-    mod tests {
+    pub(crate) mod tests {
+        // Unused until a freshly scaffolded day has any answers to check
+        #[allow(unused_imports)]
         use super::*;
 
         const RAW_INPUT: &str = $input;
 
+        /// The example's input, so other tests can reuse it
+        pub(crate) fn examples() -> Vec<(&'static str, String)> {
+            vec![("example", $crate::macros::inline_input(RAW_INPUT))]
+        }
+
         $(
           #[test]
           fn $test_part() {
-            let input: &str = &RAW_INPUT
-                .trim()
-                .lines()
-                .map(str::trim)
-                .collect::<Vec<&str>>()
-                            .join("\n");
-
-            let input = generator(input);
+            $crate::params::set_params($crate::params::Params::default());
+            let input = $crate::macros::inline_input(RAW_INPUT);
+            let input = generator(&input).expect("Unable to parse example input");
 
             let solution = super::$test_part(&input);
-            let output = format!("{}", solution);
+            let output = $crate::macros::TestOutput::render(solution);
 
-            assert_eq!(output, $test_output);
+            assert_eq!(output, $crate::answer::Answer::from($test_output));
           }
         )*
     }
-  }
+  };
+
+  (@input input $input:literal) => {
+    $crate::macros::inline_input($input)
+  };
+
+  (@input file $file:literal) => {
+    $crate::macros::fixture_input(module_path!(), $file)
+  };
+}
+
+/// Writes a line to `day`'s trace, if the runner has turned tracing on for it. The arguments are only formatted when it
+/// has.
+#[macro_export]
+macro_rules! trace {
+  ($day:expr, $($arg:tt)+) => {
+    if $crate::trace::enabled($day) {
+        $crate::trace::write($day, format_args!($($arg)+));
+    }
+  };
+}
+
+/// Strips the indentation and surrounding blank lines that come with writing an example inline in a macro.
+#[cfg(test)]
+pub(crate) fn inline_input(raw: &str) -> String {
+    raw.trim()
+        .lines()
+        .map(str::trim)
+        .collect::<Vec<&str>>()
+        .join("\n")
+}
+
+/// Finds the segment of a module path made of `prefix` and a number, returning the segment and the number
+#[cfg(test)]
+fn numbered<'a>(module: &'a str, prefix: &str) -> (&'a str, &'a str) {
+    module
+        .split("::")
+        .find_map(|segment| {
+            segment
+                .strip_prefix(prefix)
+                .filter(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()))
+                .map(|n| (segment, n))
+        })
+        .expect("Examples can only come from a day module within a year module")
+}
+
+/// The day a test belongs to, worked out from its module path
+#[cfg(test)]
+pub(crate) fn module_day(module: &str) -> u8 {
+    numbered(module, "day")
+        .1
+        .parse()
+        .expect("Days are numbered")
+}
+
+/// Reads an example from `tests/fixtures/YYYY/dayNN/`, working out the year and day from the module the test lives in.
+#[cfg(test)]
+pub(crate) fn fixture_input(module: &str, file: &str) -> String {
+    let (_, year) = numbered(module, "y");
+    let (day, _) = numbered(module, "day");
+
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join(year)
+        .join(day)
+        .join(file);
+
+    fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("Unable to read fixture {}: {}", path.display(), e))
+}
+
+/// Lets `aoc_test!` check parts that can fail alongside parts that always produce an answer.
+#[cfg(test)]
+pub(crate) trait TestOutput {
+    fn render(self) -> Answer;
+}
+
+#[cfg(test)]
+impl<T: Into<Answer>, E: Display> TestOutput for Result<T, E> {
+    fn render(self) -> Answer {
+        self.unwrap_or_else(|e| panic!("Part failed: {}", e)).into()
+    }
 }
+
+macro_rules! impl_test_output {
+    ($($t:ty),*) => {
+        $(
+          #[cfg(test)]
+          impl TestOutput for $t {
+              fn render(self) -> Answer {
+                  Answer::from(self)
+              }
+          }
+        )*
+    };
+}
+
+impl_test_output!(u16, u32, u64, u128, i32, i64, usize, String);
@@ -0,0 +1,258 @@
+//! The same functions as `math`, for `BigInt`s. None of them can overflow, so the only errors are bad moduli, missing
+//! inverses and inconsistent congruences.
+
+use num_bigint::BigInt;
+use std::collections::HashMap;
+
+use super::{Congruence, MathError};
+
+/// `x ≡ residue (mod modulus)`, reducing the residue
+pub fn congruence(residue: BigInt, modulus: BigInt) -> Result<Congruence<BigInt>, MathError> {
+    Ok(Congruence {
+        residue: modulo(&residue, &modulus)?,
+        modulus,
+    })
+}
+
+impl Congruence<BigInt> {
+    fn describe(&self) -> Congruence<String> {
+        Congruence {
+            residue: self.residue.to_string(),
+            modulus: self.modulus.to_string(),
+        }
+    }
+}
+
+impl From<Congruence<i128>> for Congruence<BigInt> {
+    fn from(congruence: Congruence<i128>) -> Congruence<BigInt> {
+        Congruence {
+            residue: congruence.residue.into(),
+            modulus: congruence.modulus.into(),
+        }
+    }
+}
+
+fn zero() -> BigInt {
+    BigInt::from(0)
+}
+
+fn one() -> BigInt {
+    BigInt::from(1)
+}
+
+fn check_modulus(modulus: &BigInt) -> Result<(), MathError> {
+    if *modulus <= zero() {
+        return Err(MathError::InvalidModulus(modulus.to_string()));
+    }
+
+    Ok(())
+}
+
+/// `a` reduced to between zero and `modulus`, which unlike `%` is never negative
+pub fn modulo(a: &BigInt, modulus: &BigInt) -> Result<BigInt, MathError> {
+    check_modulus(modulus)?;
+
+    Ok(((a % modulus) + modulus) % modulus)
+}
+
+/// `(g, x, y)` where `g` is the non-negative gcd of `a` and `b`, and `a * x + b * y = g`
+pub fn ext_gcd(a: &BigInt, b: &BigInt) -> (BigInt, BigInt, BigInt) {
+    ring_algorithm::normalized_extended_euclidian_algorithm(a.clone(), b.clone())
+}
+
+/// The `x` between zero and `modulus` with `a * x ≡ 1 (mod modulus)`
+pub fn mod_inverse(a: &BigInt, modulus: &BigInt) -> Result<BigInt, MathError> {
+    let a = modulo(a, modulus)?;
+
+    match ring_algorithm::modulo_inverse(a.clone(), modulus.clone()) {
+        Some(inverse) => modulo(&inverse, modulus),
+        None => Err(MathError::NotInvertible {
+            value: a.to_string(),
+            modulus: modulus.to_string(),
+        }),
+    }
+}
+
+/// `base` to the power of `exp`, modulo `modulus`
+pub fn pow_mod(base: &BigInt, exp: &BigInt, modulus: &BigInt) -> Result<BigInt, MathError> {
+    check_modulus(modulus)?;
+
+    if *exp < zero() {
+        return pow_mod(&mod_inverse(base, modulus)?, &-exp, modulus);
+    }
+
+    Ok(modulo(base, modulus)?.modpow(exp, modulus))
+}
+
+/// The congruence every `x` satisfying both `a` and `b` satisfies. The moduli don't need to be coprime, but if they
+/// aren't the residues have to agree modulo their gcd.
+pub fn merge(
+    a: &Congruence<BigInt>,
+    b: &Congruence<BigInt>,
+) -> Result<Congruence<BigInt>, MathError> {
+    check_modulus(&a.modulus)?;
+    check_modulus(&b.modulus)?;
+
+    let (g, _, _) = ext_gcd(&a.modulus, &b.modulus);
+    let difference = &b.residue - &a.residue;
+
+    if &difference % &g != zero() {
+        return Err(MathError::Inconsistent {
+            first: a.describe(),
+            second: b.describe(),
+        });
+    }
+
+    // The same as `math::merge`, without having to dodge overflows
+    let step = &b.modulus / &g;
+    let k = modulo(
+        &(&difference / &g * mod_inverse(&(&a.modulus / &g), &step)?),
+        &step,
+    )?;
+    let modulus = &a.modulus / &g * &b.modulus;
+
+    Ok(Congruence {
+        residue: modulo(&(&a.residue + &a.modulus * k), &modulus)?,
+        modulus,
+    })
+}
+
+/// Solves a system of congruences by the Chinese remainder theorem, giving the congruence that describes every
+/// solution, or `x ≡ 0 (mod 1)` for no congruences at all
+pub fn crt(congruences: &[Congruence<BigInt>]) -> Result<Congruence<BigInt>, MathError> {
+    congruences
+        .iter()
+        .try_fold(congruence(zero(), one())?, |system, congruence| {
+            merge(&system, congruence)
+        })
+}
+
+/// The smallest `x` with `base^x ≡ target (mod modulus)`, if there is one, by baby-step giant-step. `base` doesn't
+/// need to be coprime with the modulus. Takes time and memory in the order of the modulus's square root.
+pub fn discrete_log(
+    base: &BigInt,
+    target: &BigInt,
+    modulus: &BigInt,
+) -> Result<Option<BigInt>, MathError> {
+    let (mut base, mut target, mut modulus) = (
+        modulo(base, modulus)?,
+        modulo(target, modulus)?,
+        modulus.clone(),
+    );
+
+    // Divide out the factors base shares with the modulus, which only matter for the first few powers
+    let mut offset = zero();
+    let mut scale = modulo(&one(), &modulus)?;
+    loop {
+        if target == scale {
+            return Ok(Some(offset));
+        }
+
+        let (g, _, _) = ext_gcd(&base, &modulus);
+        if g == one() {
+            break;
+        }
+        if &target % &g != zero() {
+            return Ok(None);
+        }
+
+        target /= &g;
+        modulus /= &g;
+        offset += 1;
+        scale = scale * (&base / &g) % &modulus;
+        base %= &modulus;
+        target %= &modulus;
+    }
+
+    // Now scale * base^x ≡ target with base invertible: x = n * i - j for giant steps i and baby steps j
+    let n = modulus.sqrt() + 1;
+
+    let mut baby = HashMap::new();
+    let mut value = target;
+    let mut j = zero();
+    while j < n {
+        // Later, larger j give smaller x for the same giant step
+        baby.insert(value.clone(), j.clone());
+        value = value * &base % &modulus;
+        j += 1;
+    }
+
+    let giant = base.modpow(&n, &modulus);
+    let mut value = scale;
+    let mut i = one();
+    while i <= n {
+        value = value * &giant % &modulus;
+
+        if let Some(j) = baby.get(&value) {
+            return Ok(Some(&n * &i - j + offset));
+        }
+
+        i += 1;
+    }
+
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn congruence(residue: i128, modulus: i128) -> Congruence<BigInt> {
+        super::congruence(residue.into(), modulus.into()).unwrap()
+    }
+
+    #[test]
+    fn agrees_with_i128() {
+        for a in -20i128..20 {
+            for m in 1i128..20 {
+                let (big_a, big_m) = (BigInt::from(a), BigInt::from(m));
+
+                let (g, x, y) = ext_gcd(&big_a, &big_m);
+                assert_eq!(&big_a * x + &big_m * y, g);
+                assert_eq!(g, BigInt::from(super::super::ext_gcd(a, m).unwrap().0));
+
+                assert_eq!(
+                    mod_inverse(&big_a, &big_m).ok(),
+                    super::super::mod_inverse(a, m).ok().map(BigInt::from)
+                );
+                assert_eq!(
+                    discrete_log(&big_a, &BigInt::from(7), &big_m),
+                    Ok(super::super::discrete_log(a, 7, m)
+                        .unwrap()
+                        .map(BigInt::from))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn beyond_i128() {
+        let system = [
+            congruence(1, 1 << 100),
+            congruence(0, (1 << 100) - 1),
+            // Sharing a factor with each of the others
+            congruence(3, 6),
+        ];
+        let solution = crt(&system).unwrap();
+
+        for congruence in &system {
+            assert_eq!(&solution.residue % &congruence.modulus, congruence.residue);
+        }
+        assert_eq!(
+            solution.modulus,
+            BigInt::from(1i128 << 100) * ((1i128 << 100) - 1)
+        );
+
+        assert!(matches!(
+            crt(&[congruence(3, 4), congruence(4, 6)]),
+            Err(MathError::Inconsistent { .. })
+        ));
+
+        let prime = BigInt::from(i128::MAX);
+        assert_eq!(pow_mod(&BigInt::from(3), &(&prime - 1), &prime), Ok(one()));
+        assert_eq!(
+            pow_mod(&BigInt::from(3), &BigInt::from(-1), &BigInt::from(11)),
+            Ok(BigInt::from(4))
+        );
+    }
+}
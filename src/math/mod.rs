@@ -0,0 +1,383 @@
+//! Number theory for puzzles built on modular arithmetic. Every function works on `i128`, checking for overflow instead
+//! of wrapping, and `big` has the same functions for `BigInt` when even that isn't enough.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::error::SolveError;
+
+pub mod big;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MathError {
+    /// A result or an intermediate value didn't fit in an `i128`
+    Overflow,
+    /// Moduli have to be positive
+    InvalidModulus(String),
+    /// The value shares a factor with the modulus, so has no inverse
+    NotInvertible { value: String, modulus: String },
+    /// Two congruences can't both hold, as their residues differ by something their moduli's gcd doesn't divide
+    Inconsistent {
+        first: Congruence<String>,
+        second: Congruence<String>,
+    },
+}
+
+impl fmt::Display for MathError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MathError::Overflow => write!(f, "Overflow"),
+            MathError::InvalidModulus(modulus) => write!(f, "Invalid modulus {}", modulus),
+            MathError::NotInvertible { value, modulus } => {
+                write!(f, "{} has no inverse modulo {}", value, modulus)
+            }
+            MathError::Inconsistent { first, second } => {
+                write!(f, "{} and {} can't both hold", first, second)
+            }
+        }
+    }
+}
+
+impl std::error::Error for MathError {}
+
+/// Puzzles only do maths on well formed input, so whatever went wrong means the input has no answer
+impl From<MathError> for SolveError {
+    fn from(e: MathError) -> SolveError {
+        SolveError::NoAnswer(e.to_string())
+    }
+}
+
+/// `x ≡ residue (mod modulus)`, with the residue kept between zero and the modulus
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Congruence<T> {
+    pub residue: T,
+    pub modulus: T,
+}
+
+impl<T: fmt::Display> fmt::Display for Congruence<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "x = {} (mod {})", self.residue, self.modulus)
+    }
+}
+
+/// `x ≡ residue (mod modulus)`, reducing the residue
+pub fn congruence(residue: i128, modulus: i128) -> Result<Congruence<i128>, MathError> {
+    Ok(Congruence {
+        residue: modulo(residue, modulus)?,
+        modulus,
+    })
+}
+
+impl Congruence<i128> {
+    fn describe(&self) -> Congruence<String> {
+        Congruence {
+            residue: self.residue.to_string(),
+            modulus: self.modulus.to_string(),
+        }
+    }
+}
+
+fn check_modulus(modulus: i128) -> Result<(), MathError> {
+    if modulus <= 0 {
+        return Err(MathError::InvalidModulus(modulus.to_string()));
+    }
+
+    Ok(())
+}
+
+/// `a` reduced to between zero and `modulus`, which unlike `%` is never negative
+pub fn modulo(a: i128, modulus: i128) -> Result<i128, MathError> {
+    check_modulus(modulus)?;
+
+    Ok(a.rem_euclid(modulus))
+}
+
+/// `(g, x, y)` where `g` is the non-negative gcd of `a` and `b`, and `a * x + b * y = g`
+pub fn ext_gcd(a: i128, b: i128) -> Result<(i128, i128, i128), MathError> {
+    let step = |old: i128, new: i128, q: i128| {
+        q.checked_mul(new)
+            .and_then(|qn| old.checked_sub(qn))
+            .ok_or(MathError::Overflow)
+    };
+
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let q = old_r / r;
+
+        let next = (step(old_r, r, q)?, step(old_x, x, q)?, step(old_y, y, q)?);
+        old_r = r;
+        old_x = x;
+        old_y = y;
+        r = next.0;
+        x = next.1;
+        y = next.2;
+    }
+
+    if old_r < 0 {
+        let negate = |n: i128| n.checked_neg().ok_or(MathError::Overflow);
+
+        return Ok((negate(old_r)?, negate(old_x)?, negate(old_y)?));
+    }
+
+    Ok((old_r, old_x, old_y))
+}
+
+/// The `x` between zero and `modulus` with `a * x ≡ 1 (mod modulus)`
+pub fn mod_inverse(a: i128, modulus: i128) -> Result<i128, MathError> {
+    let a = modulo(a, modulus)?;
+    let (g, x, _) = ext_gcd(a, modulus)?;
+
+    if g != 1 {
+        return Err(MathError::NotInvertible {
+            value: a.to_string(),
+            modulus: modulus.to_string(),
+        });
+    }
+
+    modulo(x, modulus)
+}
+
+/// `a + b` modulo `modulus`, for `a` and `b` already reduced, without overflowing however big the modulus is
+fn add_mod(a: i128, b: i128, modulus: i128) -> i128 {
+    if a >= modulus - b {
+        a - (modulus - b)
+    } else {
+        a + b
+    }
+}
+
+/// `a * b` modulo `modulus`. Products too big for an `i128` are built up by doubling, so only the modulus has to fit.
+pub fn mul_mod(a: i128, b: i128, modulus: i128) -> Result<i128, MathError> {
+    let (mut a, mut b) = (modulo(a, modulus)?, modulo(b, modulus)?);
+
+    if let Some(product) = a.checked_mul(b) {
+        return Ok(product % modulus);
+    }
+
+    let mut product = 0;
+    while b > 0 {
+        if b & 1 == 1 {
+            product = add_mod(product, a, modulus);
+        }
+
+        a = add_mod(a, a, modulus);
+        b >>= 1;
+    }
+
+    Ok(product)
+}
+
+/// `base` to the power of `exp`, modulo `modulus`
+pub fn pow_mod(base: i128, mut exp: u128, modulus: i128) -> Result<i128, MathError> {
+    let mut base = modulo(base, modulus)?;
+    let mut result = 1 % modulus;
+
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, base, modulus)?;
+        }
+
+        base = mul_mod(base, base, modulus)?;
+        exp >>= 1;
+    }
+
+    Ok(result)
+}
+
+fn lcm(a: i128, b: i128, gcd: i128) -> Result<i128, MathError> {
+    (a / gcd).checked_mul(b).ok_or(MathError::Overflow)
+}
+
+/// The congruence every `x` satisfying both `a` and `b` satisfies. The moduli don't need to be coprime, but if they
+/// aren't the residues have to agree modulo their gcd.
+pub fn merge(a: &Congruence<i128>, b: &Congruence<i128>) -> Result<Congruence<i128>, MathError> {
+    check_modulus(a.modulus)?;
+    check_modulus(b.modulus)?;
+
+    let (g, _, _) = ext_gcd(a.modulus, b.modulus)?;
+    let difference = b
+        .residue
+        .checked_sub(a.residue)
+        .ok_or(MathError::Overflow)?;
+
+    if difference % g != 0 {
+        return Err(MathError::Inconsistent {
+            first: a.describe(),
+            second: b.describe(),
+        });
+    }
+
+    // x = a.residue + a.modulus * k, where a.modulus * k ≡ difference (mod b.modulus), so dividing through by g
+    // k ≡ (difference / g) * inverse(a.modulus / g) (mod b.modulus / g)
+    let step = b.modulus / g;
+    let k = mul_mod(difference / g, mod_inverse(a.modulus / g, step)?, step)?;
+    let modulus = lcm(a.modulus, b.modulus, g)?;
+
+    let offset = mul_mod(a.modulus, k, modulus)?;
+    Ok(Congruence {
+        residue: add_mod(modulo(a.residue, modulus)?, offset, modulus),
+        modulus,
+    })
+}
+
+/// Solves a system of congruences by the Chinese remainder theorem, giving the congruence that describes every
+/// solution, or `x ≡ 0 (mod 1)` for no congruences at all
+pub fn crt(congruences: &[Congruence<i128>]) -> Result<Congruence<i128>, MathError> {
+    congruences
+        .iter()
+        .try_fold(congruence(0, 1)?, |system, congruence| {
+            merge(&system, congruence)
+        })
+}
+
+/// The smallest `x` with `base^x ≡ target (mod modulus)`, if there is one, by baby-step giant-step. `base` doesn't
+/// need to be coprime with the modulus. Takes time and memory in the order of the modulus's square root.
+pub fn discrete_log(base: i128, target: i128, modulus: i128) -> Result<Option<i128>, MathError> {
+    let (mut base, mut target, mut modulus) =
+        (modulo(base, modulus)?, modulo(target, modulus)?, modulus);
+
+    if modulus == 1 {
+        return Ok(Some(0));
+    }
+
+    // Divide out the factors base shares with the modulus, which only matter for the first few powers
+    let mut offset = 0;
+    let mut scale = 1;
+    loop {
+        if target == scale {
+            return Ok(Some(offset));
+        }
+
+        let (g, _, _) = ext_gcd(base, modulus)?;
+        if g == 1 {
+            break;
+        }
+        if target % g != 0 {
+            return Ok(None);
+        }
+
+        target /= g;
+        modulus /= g;
+        offset += 1;
+        scale = mul_mod(scale, base / g, modulus)?;
+        base = modulo(base, modulus)?;
+        target = modulo(target, modulus)?;
+    }
+
+    // Now scale * base^x ≡ target with base invertible: x = n * i - j for giant steps i and baby steps j
+    let n = (modulus as f64).sqrt().ceil() as i128 + 1;
+
+    let mut baby = HashMap::new();
+    let mut value = target;
+    for j in 0..n {
+        // Later, larger j give smaller x for the same giant step
+        baby.insert(value, j);
+        value = mul_mod(value, base, modulus)?;
+    }
+
+    let giant = pow_mod(base, n as u128, modulus)?;
+    let mut value = scale;
+    for i in 1..=n {
+        value = mul_mod(value, giant, modulus)?;
+
+        if let Some(j) = baby.get(&value) {
+            return Ok(Some(n * i - j + offset));
+        }
+    }
+
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn congruence(residue: i128, modulus: i128) -> Congruence<i128> {
+        super::congruence(residue, modulus).unwrap()
+    }
+
+    #[test]
+    fn inverses() {
+        assert_eq!(ext_gcd(240, 46), Ok((2, -9, 47)));
+        assert_eq!(ext_gcd(-4, 6), Ok((2, 1, 1)));
+        assert_eq!(ext_gcd(0, 0), Ok((0, 1, 0)));
+
+        assert_eq!(mod_inverse(3, 11), Ok(4));
+        assert_eq!(mod_inverse(-3, 11), Ok(7));
+        assert!(matches!(
+            mod_inverse(4, 6),
+            Err(MathError::NotInvertible { .. })
+        ));
+        assert!(matches!(
+            mod_inverse(4, 0),
+            Err(MathError::InvalidModulus(_))
+        ));
+    }
+
+    #[test]
+    fn powers() {
+        assert_eq!(pow_mod(4, 13, 497), Ok(445));
+        assert_eq!(pow_mod(7, 0, 1), Ok(0));
+
+        // Squaring anything near this modulus overflows an i128 straight away
+        let prime = i128::MAX;
+        assert_eq!(pow_mod(3, (prime - 1) as u128, prime), Ok(1));
+        assert_eq!(mul_mod(prime - 1, prime - 1, prime), Ok(1));
+    }
+
+    #[test]
+    fn chinese_remainders() {
+        let system = [congruence(2, 3), congruence(3, 5), congruence(2, 7)];
+        assert_eq!(crt(&system), Ok(congruence(23, 105)));
+        assert_eq!(crt(&[]), Ok(congruence(0, 1)));
+
+        // Moduli sharing factors, consistently and not
+        assert_eq!(
+            crt(&[congruence(3, 4), congruence(5, 6)]),
+            Ok(congruence(11, 12))
+        );
+        assert!(matches!(
+            crt(&[congruence(3, 4), congruence(4, 6)]),
+            Err(MathError::Inconsistent { .. })
+        ));
+
+        let huge = congruence(1, 1 << 100);
+        assert_eq!(
+            crt(&[huge.clone(), congruence(0, 3)]).map(|c| c.modulus),
+            Ok(3 << 100)
+        );
+        assert_eq!(
+            crt(&[huge, congruence(0, (1 << 100) - 1)]),
+            Err(MathError::Overflow)
+        );
+    }
+
+    #[test]
+    fn discrete_logs() {
+        for modulus in 1..40 {
+            for base in 0..modulus {
+                for target in 0..modulus {
+                    let expected = (0..2 * modulus)
+                        .find(|&x| pow_mod(base, x as u128, modulus) == Ok(target % modulus));
+
+                    assert_eq!(
+                        discrete_log(base, target, modulus),
+                        Ok(expected),
+                        "{}^x = {} (mod {})",
+                        base,
+                        target,
+                        modulus
+                    );
+                }
+            }
+        }
+
+        let prime = 1_000_000_007;
+        let x = discrete_log(5, 8, prime).unwrap().unwrap();
+        assert!(x < prime);
+        assert_eq!(pow_mod(5, x as u128, prime), Ok(8));
+    }
+}
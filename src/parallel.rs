@@ -0,0 +1,421 @@
+use serde::Serialize;
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::answer::Answer;
+use crate::budget::{self, Limits};
+use crate::input::InputSource;
+use crate::params::{self, Params};
+use crate::solution::Part;
+use crate::years::Year;
+
+/// How one day fared when running the whole year
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Summary {
+    pub day: u8,
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+    pub elapsed_ms: f64,
+    pub error: Option<String>,
+}
+
+enum Event {
+    /// A worker has taken a day off the queue. Setting the flag tells it to stop taking more.
+    Started(u8, Instant, Arc<AtomicBool>),
+    Finished(u8, Summary),
+}
+
+type Queue = Arc<Mutex<VecDeque<u8>>>;
+
+fn solve(year: &Year, day: u8, source: &InputSource) -> Summary {
+    let entry = year
+        .get(day)
+        .expect("Days are validated against the registry");
+    let start = Instant::now();
+
+    let (part1, part2) = match source.read(day) {
+        // A panicking solver is reported like any other failure, rather than taking its worker down with it
+        Ok(input) => (
+            entry.try_run(&input, Part::One),
+            entry.try_run(&input, Part::Two),
+        ),
+        Err(e) => {
+            let e = format!("Unable to read input: {}", e);
+            return Summary {
+                day,
+                part1: None,
+                part2: None,
+                elapsed_ms: 0.0,
+                error: Some(e),
+            };
+        }
+    };
+
+    Summary {
+        day,
+        elapsed_ms: start.elapsed().as_secs_f64() * 1000.0,
+        error: part1
+            .as_ref()
+            .err()
+            .or_else(|| part2.as_ref().err())
+            .map(|e| e.to_string()),
+        part1: part1.ok(),
+        part2: part2.ok(),
+    }
+}
+
+/// The settings every worker runs its days with
+#[derive(Clone)]
+struct Worker {
+    year: &'static Year,
+    queue: Queue,
+    source: InputSource,
+    events: Sender<Event>,
+    timeout: Duration,
+    max_steps: Option<u64>,
+    params: Params,
+}
+
+fn spawn_worker(worker: Worker) {
+    let Worker {
+        year,
+        queue,
+        source,
+        events,
+        timeout,
+        max_steps,
+        params,
+    } = worker;
+
+    let cancelled = Arc::new(AtomicBool::new(false));
+
+    thread::spawn(move || {
+        params::set_params(params);
+
+        loop {
+            // A worker abandoned for running over has already been replaced, so it mustn't take any more days
+            if cancelled.load(Ordering::SeqCst) {
+                return;
+            }
+
+            let day = match queue.lock().expect("Queue lock poisoned").pop_front() {
+                Some(day) => day,
+                None => return,
+            };
+
+            let start = Instant::now();
+            if events
+                .send(Event::Started(day, start, Arc::clone(&cancelled)))
+                .is_err()
+            {
+                return;
+            }
+
+            // Solvers that check their budget stop by themselves once they're reported as timed out
+            budget::set_limits(Limits {
+                max_steps,
+                deadline: Some(start + timeout),
+            });
+
+            let summary = solve(year, day, &source);
+
+            if events.send(Event::Finished(day, summary)).is_err() {
+                return;
+            }
+        }
+    });
+}
+
+/// Runs every given day of `year` on a pool of `threads` workers. A day that takes longer than `timeout` is reported as timed out
+/// and its worker is abandoned and replaced, so one hung solver can't hold up the rest of the report. An abandoned worker
+/// finishes the day it's stuck on, if it ever does, but takes no more. `max_steps` replaces the step limit of every
+/// solver that has one, and `params` replaces the defaults of any puzzle parameters.
+pub fn run(
+    year: &'static Year,
+    days: &[u8],
+    source: &InputSource,
+    threads: usize,
+    timeout: Duration,
+    max_steps: Option<u64>,
+    params: &Params,
+) -> Vec<Summary> {
+    let (sender, events) = mpsc::channel();
+    let worker = Worker {
+        year,
+        queue: Arc::new(Mutex::new(days.iter().copied().collect())),
+        source: source.clone(),
+        events: sender,
+        timeout,
+        max_steps,
+        params: params.clone(),
+    };
+
+    for _ in 0..threads.max(1).min(days.len()) {
+        spawn_worker(worker.clone());
+    }
+
+    let mut running: HashMap<u8, (Instant, Arc<AtomicBool>)> = HashMap::new();
+    let mut summaries = Vec::new();
+
+    while summaries.len() < days.len() {
+        let next_deadline = running.values().map(|&(start, _)| start + timeout).min();
+        let wait = next_deadline
+            .map(|deadline| deadline.saturating_duration_since(Instant::now()))
+            .unwrap_or(timeout);
+
+        match events.recv_timeout(wait) {
+            Ok(Event::Started(day, start, cancelled)) => {
+                running.insert(day, (start, cancelled));
+            }
+            // Days that already timed out have been reported, so late results are dropped
+            Ok(Event::Finished(day, summary)) => {
+                if running.remove(&day).is_some() {
+                    summaries.push(summary);
+                }
+            }
+            Err(RecvTimeoutError::Timeout) => {
+                let now = Instant::now();
+                let expired: Vec<u8> = running
+                    .iter()
+                    .filter(|(_, (start, _))| now >= *start + timeout)
+                    .map(|(&day, _)| day)
+                    .collect();
+
+                for day in expired {
+                    if let Some((_, cancelled)) = running.remove(&day) {
+                        cancelled.store(true, Ordering::SeqCst);
+                    }
+                    summaries.push(Summary {
+                        day,
+                        part1: None,
+                        part2: None,
+                        elapsed_ms: timeout.as_secs_f64() * 1000.0,
+                        error: Some(format!("Timed out after {:?}", timeout)),
+                    });
+
+                    spawn_worker(worker.clone());
+                }
+            }
+            Err(RecvTimeoutError::Disconnected) => unreachable!("The coordinator holds a sender"),
+        }
+    }
+
+    summaries.sort_by_key(|summary| summary.day);
+    summaries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::{ParseError, SolveError};
+    use crate::solution::{Entry, Solution};
+    use crate::years;
+    use std::cell::RefCell;
+    use std::fs;
+    use std::sync::Barrier;
+
+    /// A day whose first part always panics
+    struct Panics;
+
+    impl Solution for Panics {
+        const DAY: u8 = 1;
+
+        type Input = ();
+        type Output1 = u32;
+        type Output2 = u32;
+
+        fn parse(_: &str) -> Result<(), ParseError> {
+            Ok(())
+        }
+
+        fn part1(_: &()) -> Result<u32, SolveError> {
+            panic!("Unable to solve")
+        }
+
+        fn part2(_: &()) -> Result<u32, SolveError> {
+            Ok(2)
+        }
+    }
+
+    static BROKEN: Year = Year {
+        year: 2020,
+        days: &[Entry::new::<Panics>()],
+    };
+
+    /// Which thread ran each `Queued` day
+    static RAN: Mutex<Vec<(u8, thread::ThreadId)>> = Mutex::new(Vec::new());
+
+    lazy_static! {
+        /// Day 1 waits here until the test lets it finish, long after it has timed out
+        static ref DAY_1_RELEASED: Barrier = Barrier::new(2);
+        /// Day 2 is run by the worker that replaced day 1's, which has already been abandoned by then
+        static ref DAY_2_STARTED: Barrier = Barrier::new(2);
+        /// Day 2 waits here, keeping the rest of the days queued, until day 1's worker has exited
+        static ref DAY_2_RELEASED: Barrier = Barrier::new(2);
+        static ref WORKER_EXITED: Barrier = Barrier::new(2);
+    }
+
+    /// Meets the test at `WORKER_EXITED` when the thread holding it exits
+    struct ExitGuard;
+
+    impl Drop for ExitGuard {
+        fn drop(&mut self) {
+            WORKER_EXITED.wait();
+        }
+    }
+
+    thread_local! {
+        static EXIT: RefCell<Option<ExitGuard>> = const { RefCell::new(None) };
+    }
+
+    /// A day that records which thread ran it. Days 1 and 2 also wait for the test to let them go.
+    struct Queued<const DAY: u8>;
+
+    impl<const DAY: u8> Solution for Queued<DAY> {
+        const DAY: u8 = DAY;
+
+        type Input = ();
+        type Output1 = u32;
+        type Output2 = u32;
+
+        fn parse(_: &str) -> Result<(), ParseError> {
+            Ok(())
+        }
+
+        fn part1(_: &()) -> Result<u32, SolveError> {
+            RAN.lock().unwrap().push((DAY, thread::current().id()));
+
+            match DAY {
+                1 => {
+                    EXIT.with(|exit| *exit.borrow_mut() = Some(ExitGuard));
+                    DAY_1_RELEASED.wait();
+                }
+                2 => {
+                    DAY_2_STARTED.wait();
+                    DAY_2_RELEASED.wait();
+                }
+                _ => (),
+            }
+
+            Ok(1)
+        }
+
+        fn part2(_: &()) -> Result<u32, SolveError> {
+            Ok(2)
+        }
+    }
+
+    static QUEUED: Year = Year {
+        year: 2020,
+        days: &[
+            Entry::new::<Queued<1>>(),
+            Entry::new::<Queued<2>>(),
+            Entry::new::<Queued<3>>(),
+            Entry::new::<Queued<4>>(),
+            Entry::new::<Queued<5>>(),
+        ],
+    };
+
+    #[test]
+    fn run_days() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(
+            dir.path().join("day1.txt"),
+            "1721\n979\n366\n299\n675\n1456\n",
+        )
+        .unwrap();
+        fs::write(dir.path().join("day15.txt"), "0,3,6").unwrap();
+
+        let summaries = run(
+            years::get(2020).unwrap(),
+            &[1, 2, 15],
+            &InputSource::Dir(dir.path().to_owned()),
+            2,
+            Duration::from_secs(60),
+            Some(1000),
+            &Params::default(),
+        );
+
+        assert_eq!(summaries.len(), 3);
+
+        assert_eq!(summaries[0].part1, Some(Answer::Int(514579)));
+        assert_eq!(summaries[0].part2, Some(Answer::Int(241861950)));
+
+        // Day 2 has no input at all
+        assert!(summaries[1]
+            .error
+            .as_ref()
+            .unwrap()
+            .starts_with("Unable to read input"));
+
+        // Day 15 plays thousands of turns, one step each
+        assert_eq!(
+            summaries[2].error,
+            Some("day 15: Did not converge within 1000 steps".to_owned())
+        );
+    }
+
+    #[test]
+    fn panicking_day() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("day1.txt"), "").unwrap();
+
+        let summaries = run(
+            &BROKEN,
+            &[1],
+            &InputSource::Dir(dir.path().to_owned()),
+            1,
+            Duration::from_secs(60),
+            None,
+            &Params::default(),
+        );
+
+        assert_eq!(
+            summaries[0].error,
+            Some("day 1: panicked: Unable to solve".to_owned())
+        );
+        assert_eq!(summaries[0].part2, Some(Answer::Int(2)));
+    }
+
+    #[test]
+    fn abandoned_workers_stop() {
+        let dir = tempfile::tempdir().unwrap();
+        let input = dir.path().join("input.txt");
+        fs::write(&input, "").unwrap();
+
+        // Day 2 only starts once day 1 has been reported as timed out and its worker replaced. Day 1 then finishes
+        // with days 3 to 5 still queued, and the rest of the run waits until its worker has exited.
+        let choreography = thread::spawn(|| {
+            DAY_2_STARTED.wait();
+            DAY_1_RELEASED.wait();
+            WORKER_EXITED.wait();
+            DAY_2_RELEASED.wait();
+        });
+
+        let days: Vec<u8> = (1..=5).collect();
+        let summaries = run(
+            &QUEUED,
+            &days,
+            &InputSource::File(input),
+            1,
+            Duration::from_millis(50),
+            None,
+            &Params::default(),
+        );
+        choreography.join().unwrap();
+
+        assert_eq!(summaries[0].error, Some("Timed out after 50ms".to_owned()));
+        assert!(summaries[1..]
+            .iter()
+            .all(|summary| summary.part1 == Some(Answer::Int(1))));
+
+        // The worker that was stuck on day 1 left the queued days to its replacement
+        let ran = RAN.lock().unwrap();
+        let abandoned = ran.iter().find(|(day, _)| *day == 1).unwrap().1;
+        assert_eq!(ran.iter().filter(|(_, id)| *id == abandoned).count(), 1);
+    }
+}
@@ -0,0 +1,237 @@
+use serde::de::DeserializeOwned;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::error::SolveError;
+use crate::years::Year;
+
+/// Where the runner looks for parameters when none are given
+pub const DEFAULT_PATH: &str = "params.toml";
+
+/// A constant a day's puzzle is built around, such as day 1's target sum. Solvers read it with `get`, which gives the
+/// default unless the runner has set something else, so variants of a puzzle can be solved without editing the day.
+pub struct Param<T> {
+    pub day: u8,
+    pub name: &'static str,
+    default: fn() -> T,
+}
+
+impl<T: DeserializeOwned> Param<T> {
+    pub const fn new(day: u8, name: &'static str, default: fn() -> T) -> Param<T> {
+        Param { day, name, default }
+    }
+
+    /// The value set for runs on this thread, or the default if there isn't one
+    pub fn get(&self) -> Result<T, SolveError> {
+        PARAMS.with(|params| match params.borrow().value(self.day, self.name) {
+            Some(value) => value
+                .clone()
+                .try_into()
+                .map_err(|e| SolveError::InvalidParameter {
+                    name: self.name.to_owned(),
+                    reason: e.to_string(),
+                }),
+            None => Ok((self.default)()),
+        })
+    }
+}
+
+#[derive(Debug)]
+pub enum ParamsError {
+    Io(PathBuf, io::Error),
+    Syntax(String),
+    /// A command line override that isn't written `day.name=value`
+    InvalidOverride(String),
+    UnknownDay(u8),
+    UnknownParam {
+        day: u8,
+        name: String,
+    },
+}
+
+impl fmt::Display for ParamsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParamsError::Io(path, e) => write!(f, "{}: {}", path.display(), e),
+            ParamsError::Syntax(e) => write!(f, "Invalid parameters: {}", e),
+            ParamsError::InvalidOverride(s) => {
+                write!(f, "{:?} isn't a parameter, write it as day.name=value", s)
+            }
+            ParamsError::UnknownDay(day) => write!(f, "Day {} isn't implemented", day),
+            ParamsError::UnknownParam { day, name } => {
+                write!(f, "Day {} has no parameter {:?}", day, name)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParamsError {}
+
+/// Parameter values for any number of days, which replace the defaults their solvers were written with
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Params {
+    days: BTreeMap<u8, toml::value::Table>,
+}
+
+impl Params {
+    /// Loads parameters from a file of `[dayNN]` tables, treating a missing file as setting nothing.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Params, ParamsError> {
+        let path = path.as_ref();
+
+        match fs::read_to_string(path) {
+            Ok(toml) => Params::parse(&toml),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Params::default()),
+            Err(e) => Err(ParamsError::Io(path.to_owned(), e)),
+        }
+    }
+
+    /// Parses parameters written as `[dayNN]` tables, e.g. `[day09]` followed by `preamble = 5`.
+    pub fn parse(toml: &str) -> Result<Params, ParamsError> {
+        let tables: toml::value::Table =
+            toml::from_str(toml).map_err(|e| ParamsError::Syntax(e.to_string()))?;
+        let mut params = Params::default();
+
+        for (key, values) in tables {
+            let day = key
+                .strip_prefix("day")
+                .and_then(|day| day.parse::<u8>().ok())
+                .ok_or_else(|| ParamsError::Syntax(format!("{:?} isn't a day", key)))?;
+
+            match values {
+                toml::Value::Table(values) => params.days.entry(day).or_default().extend(values),
+                _ => return Err(ParamsError::Syntax(format!("{} isn't a table", key))),
+            }
+        }
+
+        Ok(params)
+    }
+
+    /// Applies an override written `day.name=value`, e.g. `9.preamble=5`. The value is read as TOML, falling back to
+    /// a plain string so `7.bag=dark red` doesn't need quoting.
+    pub fn apply(&mut self, assignment: &str) -> Result<(), ParamsError> {
+        let invalid = || ParamsError::InvalidOverride(assignment.to_owned());
+
+        let (key, value) = assignment.split_once('=').ok_or_else(invalid)?;
+        let (day, name) = key.trim().split_once('.').ok_or_else(invalid)?;
+        let day = day.parse::<u8>().map_err(|_| invalid())?;
+
+        let value = toml::from_str::<toml::value::Table>(&format!("value = {}", value))
+            .ok()
+            .and_then(|mut table| table.remove("value"))
+            .unwrap_or_else(|| toml::Value::String(value.trim().to_owned()));
+
+        self.insert(day, name, value);
+        Ok(())
+    }
+
+    pub fn insert<V: Into<toml::Value>>(&mut self, day: u8, name: &str, value: V) {
+        self.days
+            .entry(day)
+            .or_default()
+            .insert(name.to_owned(), value.into());
+    }
+
+    fn value(&self, day: u8, name: &str) -> Option<&toml::Value> {
+        self.days.get(&day).and_then(|values| values.get(name))
+    }
+
+    /// Makes sure every parameter belongs to a day of `year` that reads it, so typos don't go unnoticed.
+    pub fn check(&self, year: &Year) -> Result<(), ParamsError> {
+        for (&day, values) in &self.days {
+            let entry = year.get(day).ok_or(ParamsError::UnknownDay(day))?;
+
+            if let Some(name) = values
+                .keys()
+                .find(|name| !entry.params.contains(&name.as_str()))
+            {
+                return Err(ParamsError::UnknownParam {
+                    day,
+                    name: name.clone(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+thread_local! {
+    static PARAMS: RefCell<Params> = RefCell::new(Params::default());
+}
+
+/// Sets the parameters for every solver subsequently run on this thread.
+pub fn set_params(params: Params) {
+    PARAMS.with(|cell| *cell.borrow_mut() = params);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::years;
+
+    const PREAMBLE: Param<usize> = Param::new(9, "preamble", || 25);
+
+    #[test]
+    fn overrides() {
+        set_params(Params::default());
+        assert_eq!(PREAMBLE.get(), Ok(25));
+
+        let mut params = Params::parse("[day09]\npreamble = 5\n").unwrap();
+        set_params(params.clone());
+        assert_eq!(PREAMBLE.get(), Ok(5));
+
+        params.apply("9.preamble=7").unwrap();
+        params.apply("7.bag=dark red").unwrap();
+        params.apply("3.slopes=[[1, 2]]").unwrap();
+        assert_eq!(params.value(7, "bag"), Some(&toml::Value::from("dark red")));
+        assert_eq!(
+            params.value(3, "slopes"),
+            Some(&toml::Value::from(vec![vec![1, 2]]))
+        );
+
+        set_params(params);
+        assert_eq!(PREAMBLE.get(), Ok(7));
+
+        params = Params::default();
+        params.insert(9, "preamble", "five");
+        set_params(params);
+        assert!(matches!(
+            PREAMBLE.get(),
+            Err(SolveError::InvalidParameter { .. })
+        ));
+
+        set_params(Params::default());
+    }
+
+    #[test]
+    fn rejects_mistakes() {
+        let year = years::get(2020).unwrap();
+
+        assert!(Params::parse("[day09]\npreamble = 5\n")
+            .unwrap()
+            .check(year)
+            .is_ok());
+        assert!(matches!(
+            Params::parse("[day09]\npremable = 5\n")
+                .unwrap()
+                .check(year),
+            Err(ParamsError::UnknownParam { day: 9, .. })
+        ));
+        assert!(matches!(
+            Params::parse("[day26]\nx = 1\n").unwrap().check(year),
+            Err(ParamsError::UnknownDay(26))
+        ));
+        assert!(matches!(
+            Params::parse("[nine]\npreamble = 5\n"),
+            Err(ParamsError::Syntax(_))
+        ));
+        assert!(matches!(
+            Params::default().apply("preamble=5"),
+            Err(ParamsError::InvalidOverride(_))
+        ));
+    }
+}
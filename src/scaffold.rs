@@ -0,0 +1,358 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::years;
+
+/// The module every new day starts from, with `{{day}}` and `{{padded}}` standing in for its number
+const TEMPLATE: &str = include_str!("../templates/day.rs");
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    InvalidDay(u8),
+    /// New years need their module registered by hand before days can be added to them
+    NoYear(PathBuf),
+    /// The day's module or fuzz target is already there, and scaffolding never overwrites anything
+    Exists(PathBuf),
+    /// A registry already lists the day, though its module is missing
+    Registered(PathBuf),
+    /// The fuzz manifest already has a target with the day's name
+    Listed(PathBuf, String),
+    /// A file that lists every day doesn't have a recognisable list to add the new day to
+    NoRegistry(PathBuf),
+    Io(PathBuf, io::Error),
+}
+
+impl fmt::Display for ScaffoldError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ScaffoldError::InvalidDay(day) => write!(f, "Day {} isn't between 1 and 25", day),
+            ScaffoldError::NoYear(path) => write!(
+                f,
+                "{} doesn't exist, create it and register it in src/years/mod.rs first",
+                path.display()
+            ),
+            ScaffoldError::Exists(path) => write!(f, "{} already exists", path.display()),
+            ScaffoldError::Registered(path) => {
+                write!(f, "The day is already registered in {}", path.display())
+            }
+            ScaffoldError::Listed(path, name) => {
+                write!(f, "{} already has a target named {}", path.display(), name)
+            }
+            ScaffoldError::NoRegistry(path) => {
+                write!(
+                    f,
+                    "Unable to find where to register the day in {}",
+                    path.display()
+                )
+            }
+            ScaffoldError::Io(path, e) => write!(f, "{}: {}", path.display(), e),
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {}
+
+/// Creates everything a new day of `year` needs under the repository at `root`: its module from the template, its
+/// entries in `src/years/yYYYY/mod.rs`, a fuzz target, an empty input file and a fixtures directory. Returns every path
+/// it created or changed. Nothing is written unless the day's module and fuzz target don't exist yet, no registry
+/// lists the day already and every registry can be updated.
+pub fn scaffold(root: &Path, year: u16, day: u8) -> Result<Vec<PathBuf>, ScaffoldError> {
+    if !(1..=25).contains(&day) {
+        return Err(ScaffoldError::InvalidDay(day));
+    }
+
+    let year_dir = root.join("src").join("years").join(format!("y{}", year));
+    if !year_dir.is_dir() {
+        return Err(ScaffoldError::NoYear(year_dir));
+    }
+
+    let module = year_dir.join(format!("day{:02}.rs", day));
+    if module.exists() {
+        return Err(ScaffoldError::Exists(module));
+    }
+
+    let registry = year_dir.join("mod.rs");
+    let entries: [fn(u8) -> String; 3] = [
+        |day| format!("pub mod day{:02};", day),
+        |day| format!("Entry::new::<day{:02}::Day{:02}>(),", day, day),
+        |day| format!("({}, day{:02}::tests::examples()),", day, day),
+    ];
+
+    let mut days = read(&registry)?;
+    if days
+        .lines()
+        .any(|line| entries.iter().any(|entry| line.trim() == entry(day)))
+    {
+        return Err(ScaffoldError::Registered(registry));
+    }
+
+    for entry in &entries {
+        days = register(&days, day, entry)
+            .ok_or_else(|| ScaffoldError::NoRegistry(registry.clone()))?;
+    }
+
+    let target = format!("y{}_day{:02}", year, day);
+    let fuzz_manifest = root.join("fuzz").join("Cargo.toml");
+    let fuzz_target = root
+        .join("fuzz")
+        .join("fuzz_targets")
+        .join(format!("{}.rs", target));
+    if fuzz_target.exists() {
+        return Err(ScaffoldError::Exists(fuzz_target));
+    }
+
+    let mut manifest = read(&fuzz_manifest)?;
+    if manifest
+        .lines()
+        .any(|line| line.trim() == format!("name = \"{}\"", target))
+    {
+        return Err(ScaffoldError::Listed(fuzz_manifest, target));
+    }
+
+    manifest.push_str(&format!(
+        "\n[[bin]]\nname = \"{}\"\npath = \"fuzz_targets/{}.rs\"\ntest = false\ndoc = false\n",
+        target, target
+    ));
+
+    let input = root
+        .join("input")
+        .join(year.to_string())
+        .join(format!("day{}.txt", day));
+    let fixtures = root
+        .join("tests")
+        .join("fixtures")
+        .join(year.to_string())
+        .join(format!("day{:02}", day));
+
+    write(&module, &render(year, day))?;
+    write(&registry, &days)?;
+    write(&fuzz_manifest, &manifest)?;
+    write(
+        &fuzz_target,
+        &format!(
+            "#![no_main]\nuse libfuzzer_sys::fuzz_target;\n\nfuzz_target!(|input: &str| {{\n    \
+             aoc_2020::fuzzing::parsers({}, {}, input);\n}});\n",
+            year, day
+        ),
+    )?;
+
+    let mut created = vec![module, registry, fuzz_manifest, fuzz_target];
+
+    // The input may already have been downloaded, and fixtures may already be waiting
+    if !input.exists() {
+        create_dir(input.parent().expect("Inputs live in a directory"))?;
+        write(&input, "")?;
+        created.push(input);
+    }
+
+    if !fixtures.exists() {
+        create_dir(&fixtures)?;
+        write(&fixtures.join(".gitkeep"), "")?;
+        created.push(fixtures);
+    }
+
+    Ok(created)
+}
+
+/// The template filled in for `day` of `year`. cargo-aoc only runs one year, so other years' days leave out its
+/// attributes.
+pub fn render(year: u16, day: u8) -> String {
+    let template: String = if year == years::RUNNER_YEAR {
+        TEMPLATE.to_owned()
+    } else {
+        TEMPLATE
+            .lines()
+            .filter(|line| !line.starts_with("#[aoc") && !line.starts_with("use aoc_runner_derive"))
+            .map(|line| line.to_owned() + "\n")
+            .collect()
+    };
+
+    template
+        .replace("{{padded}}", &format!("{:02}", day))
+        .replace("{{day}}", &day.to_string())
+}
+
+/// Adds `entry(day)` to the list of `entry(n)` lines in `source`, keeping the list in day order and matching the
+/// indentation of its neighbours. Returns `None` if `source` has no such list.
+fn register(source: &str, day: u8, entry: impl Fn(u8) -> String) -> Option<String> {
+    let mut lines: Vec<String> = source.lines().map(str::to_owned).collect();
+
+    let listed: Vec<(usize, u8)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| (1..=25).find(|&n| line.trim() == entry(n)).map(|n| (i, n)))
+        .collect();
+
+    let &(neighbour, _) = listed.first()?;
+    let position = listed
+        .iter()
+        .rev()
+        .find(|&&(_, n)| n < day)
+        .map_or(neighbour, |&(i, _)| i + 1);
+
+    let indent: String = lines[neighbour]
+        .chars()
+        .take_while(|c| c.is_whitespace())
+        .collect();
+    lines.insert(position, indent + &entry(day));
+
+    Some(lines.join("\n") + "\n")
+}
+
+fn read(path: &Path) -> Result<String, ScaffoldError> {
+    fs::read_to_string(path).map_err(|e| ScaffoldError::Io(path.to_owned(), e))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), ScaffoldError> {
+    fs::write(path, contents).map_err(|e| ScaffoldError::Io(path.to_owned(), e))
+}
+
+fn create_dir(path: &Path) -> Result<(), ScaffoldError> {
+    fs::create_dir_all(path).map_err(|e| ScaffoldError::Io(path.to_owned(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REGISTRY: &str = "\
+pub mod day01;
+pub mod day03;
+
+pub static ALL: &[Entry] = &[
+    Entry::new::<day01::Day01>(),
+    Entry::new::<day03::Day03>(),
+];
+
+fn examples() {
+    let examples = vec![
+        (1, day01::tests::examples()),
+        (3, day03::tests::examples()),
+    ];
+}
+";
+
+    /// A repository with days 1 and 3 of 2020, laid out like this one
+    fn repository() -> tempfile::TempDir {
+        let root = tempfile::tempdir().unwrap();
+        let year = root.path().join("src").join("years").join("y2020");
+
+        fs::create_dir_all(&year).unwrap();
+        fs::create_dir_all(root.path().join("fuzz").join("fuzz_targets")).unwrap();
+        fs::write(year.join("mod.rs"), REGISTRY).unwrap();
+        fs::write(year.join("day01.rs"), "").unwrap();
+        fs::write(year.join("day03.rs"), "").unwrap();
+        fs::write(root.path().join("fuzz").join("Cargo.toml"), "[package]\n").unwrap();
+
+        root
+    }
+
+    #[test]
+    fn registers_in_order() {
+        let root = repository();
+        scaffold(root.path(), 2020, 2).unwrap();
+        scaffold(root.path(), 2020, 4).unwrap();
+
+        let registry = fs::read_to_string(root.path().join("src/years/y2020/mod.rs")).unwrap();
+        let expected = REGISTRY
+            .replace("pub mod day03;", "pub mod day02;\npub mod day03;\npub mod day04;")
+            .replace(
+                "    Entry::new::<day03::Day03>(),",
+                "    Entry::new::<day02::Day02>(),\n    Entry::new::<day03::Day03>(),\n    \
+                 Entry::new::<day04::Day04>(),",
+            )
+            .replace(
+                "        (3, day03::tests::examples()),",
+                "        (2, day02::tests::examples()),\n        (3, day03::tests::examples()),\n        \
+                 (4, day04::tests::examples()),",
+            );
+        assert_eq!(registry, expected);
+
+        let module = fs::read_to_string(root.path().join("src/years/y2020/day02.rs")).unwrap();
+        assert!(module.contains("impl Solution for Day02 {"));
+        assert!(module.contains("#[aoc(day2, part1)]"));
+        assert!(!module.contains("{{"));
+
+        assert!(root.path().join("input/2020/day2.txt").exists());
+        assert!(root.path().join("tests/fixtures/2020/day02").is_dir());
+        assert!(root
+            .path()
+            .join("fuzz/fuzz_targets/y2020_day04.rs")
+            .exists());
+    }
+
+    #[test]
+    fn refuses_to_overwrite() {
+        let root = repository();
+
+        assert!(matches!(
+            scaffold(root.path(), 2020, 3),
+            Err(ScaffoldError::Exists(_))
+        ));
+        assert!(matches!(
+            scaffold(root.path(), 2020, 26),
+            Err(ScaffoldError::InvalidDay(26))
+        ));
+        assert!(matches!(
+            scaffold(root.path(), 2021, 1),
+            Err(ScaffoldError::NoYear(_))
+        ));
+        assert_eq!(
+            fs::read_to_string(root.path().join("src/years/y2020/mod.rs")).unwrap(),
+            REGISTRY
+        );
+    }
+
+    #[test]
+    fn scaffolds_each_day_once() {
+        let root = repository();
+        let registry = root.path().join("src/years/y2020/mod.rs");
+        let module = root.path().join("src/years/y2020/day02.rs");
+        let manifest = root.path().join("fuzz/Cargo.toml");
+        let target = root.path().join("fuzz/fuzz_targets/y2020_day02.rs");
+
+        scaffold(root.path(), 2020, 2).unwrap();
+        let scaffolded = fs::read_to_string(&manifest).unwrap();
+        assert_eq!(scaffolded.matches("[[bin]]").count(), 1);
+
+        assert!(matches!(
+            scaffold(root.path(), 2020, 2),
+            Err(ScaffoldError::Exists(path)) if path == module
+        ));
+
+        // Whatever is left of the day after its module goes, it still isn't scaffolded again
+        fs::remove_file(&module).unwrap();
+        assert!(matches!(
+            scaffold(root.path(), 2020, 2),
+            Err(ScaffoldError::Registered(path)) if path == registry
+        ));
+
+        fs::write(&registry, REGISTRY).unwrap();
+        assert!(matches!(
+            scaffold(root.path(), 2020, 2),
+            Err(ScaffoldError::Exists(path)) if path == target
+        ));
+
+        fs::remove_file(&target).unwrap();
+        assert!(matches!(
+            scaffold(root.path(), 2020, 2),
+            Err(ScaffoldError::Listed(path, name)) if path == manifest && name == "y2020_day02"
+        ));
+
+        assert!(!module.exists());
+        assert!(!target.exists());
+        assert_eq!(fs::read_to_string(&registry).unwrap(), REGISTRY);
+        assert_eq!(fs::read_to_string(&manifest).unwrap(), scaffolded);
+    }
+
+    #[test]
+    fn other_years_skip_cargo_aoc() {
+        let module = render(2021, 5);
+
+        assert!(module.contains("impl Solution for Day05 {"));
+        assert!(!module.contains("#[aoc"));
+        assert!(!module.contains("aoc_runner_derive"));
+    }
+}
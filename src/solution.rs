@@ -0,0 +1,155 @@
+use std::panic;
+
+use crate::answer::Answer;
+use crate::bench::{self, DayReport};
+use crate::error::{ParseError, RunError, SolveError};
+
+/// A solver for a single day's puzzle. Both parts share the input produced by `parse`.
+pub trait Solution {
+    /// The day of December this puzzle was released on
+    const DAY: u8;
+
+    /// The names of the `Param`s this day reads, which are the only ones the runner will let be set for it
+    const PARAMS: &'static [&'static str] = &[];
+
+    /// The parsed puzzle input
+    type Input;
+    type Output1: Into<Answer>;
+    type Output2: Into<Answer>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    /// Parses `input` without stopping at the first problem, returning every one found. Days whose parser can't carry
+    /// on past a bad line only report the first.
+    fn check(input: &str) -> Vec<ParseError> {
+        Self::parse(input).err().into_iter().collect()
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Output1, SolveError>;
+    fn part2(input: &Self::Input) -> Result<Self::Output2, SolveError>;
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+/// A type-erased `Solution`, so that days with different input and output types can live side by side in the registry.
+pub struct Entry {
+    pub day: u8,
+    pub params: &'static [&'static str],
+    solve: fn(&str, Part) -> Result<Answer, RunError>,
+    bench: fn(&str, usize) -> Result<DayReport, RunError>,
+    check: fn(&str) -> Vec<ParseError>,
+    parse: fn(&str) -> Result<(), ParseError>,
+}
+
+impl Entry {
+    pub const fn new<S: Solution>() -> Entry {
+        Entry {
+            day: S::DAY,
+            params: S::PARAMS,
+            solve: solve::<S>,
+            bench: bench::run::<S>,
+            check: S::check,
+            parse: parse::<S>,
+        }
+    }
+
+    /// Parses `input` and runs the requested part on it.
+    pub fn run(&self, input: &str, part: Part) -> Result<Answer, RunError> {
+        (self.solve)(input, part)
+    }
+
+    /// Like `run`, but a panicking solver is reported as a `RunError::Panicked` instead of unwinding into the caller, so
+    /// one broken day can't take the rest of a run down with it.
+    pub fn try_run(&self, input: &str, part: Part) -> Result<Answer, RunError> {
+        panic::catch_unwind(|| self.run(input, part)).unwrap_or_else(|payload| {
+            let message = match payload.downcast::<String>() {
+                Ok(message) => *message,
+                Err(payload) => match payload.downcast::<&str>() {
+                    Ok(message) => message.to_string(),
+                    Err(_) => "Unknown panic".to_owned(),
+                },
+            };
+
+            Err(RunError::Panicked {
+                day: self.day,
+                message,
+            })
+        })
+    }
+
+    /// Parses `input` without running either part, throwing the parsed input away.
+    pub fn parse(&self, input: &str) -> Result<(), ParseError> {
+        (self.parse)(input)
+    }
+
+    /// Lists every malformed part of `input` without running either part.
+    pub fn check(&self, input: &str) -> Vec<ParseError> {
+        (self.check)(input)
+    }
+
+    /// Times the generator and each part separately over `runs` runs.
+    pub fn bench(&self, input: &str, runs: usize) -> Result<DayReport, RunError> {
+        (self.bench)(input, runs)
+    }
+}
+
+fn parse<S: Solution>(input: &str) -> Result<(), ParseError> {
+    S::parse(input).map(drop)
+}
+
+fn solve<S: Solution>(input: &str, part: Part) -> Result<Answer, RunError> {
+    let input = S::parse(input)?;
+
+    let answer = match part {
+        Part::One => S::part1(&input).map(|answer| answer.into()),
+        Part::Two => S::part2(&input).map(|answer| answer.into()),
+    };
+
+    answer.map_err(|error| RunError::Solve { day: S::DAY, error })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Counts the lines of its input, and can't count to two
+    struct Broken;
+
+    impl Solution for Broken {
+        const DAY: u8 = 1;
+
+        type Input = usize;
+        type Output1 = usize;
+        type Output2 = usize;
+
+        fn parse(input: &str) -> Result<usize, ParseError> {
+            Ok(input.lines().count())
+        }
+
+        fn part1(input: &usize) -> Result<usize, SolveError> {
+            Ok(*input)
+        }
+
+        fn part2(input: &usize) -> Result<usize, SolveError> {
+            panic!("Unable to count {} lines twice", input)
+        }
+    }
+
+    #[test]
+    fn panics_are_caught() {
+        let entry = Entry::new::<Broken>();
+
+        assert_eq!(entry.try_run("a\nb", Part::One), Ok(Answer::Int(2)));
+        assert_eq!(
+            entry.try_run("a\nb", Part::Two),
+            Err(RunError::Panicked {
+                day: 1,
+                message: "Unable to count 2 lines twice".to_owned()
+            })
+        );
+    }
+}
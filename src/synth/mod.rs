@@ -0,0 +1,148 @@
+use std::ops::Range;
+
+use crate::answer::Answer;
+
+pub mod y2020;
+
+/// A generated puzzle input along with the answers it was built to have
+#[derive(Debug, Clone, PartialEq)]
+pub struct Synthetic {
+    pub input: String,
+    pub part1: Answer,
+    pub part2: Answer,
+}
+
+/// Builds inputs of a given size for one day. What the size counts (lines, passports, bags...) depends on the day, and
+/// is capped where the puzzle's answer types or rules don't allow arbitrarily large inputs.
+pub struct Generator {
+    pub day: u8,
+    pub max_size: usize,
+    generate: fn(&mut Rng, usize) -> Synthetic,
+}
+
+impl Generator {
+    const fn new(
+        day: u8,
+        max_size: usize,
+        generate: fn(&mut Rng, usize) -> Synthetic,
+    ) -> Generator {
+        Generator {
+            day,
+            max_size,
+            generate,
+        }
+    }
+
+    /// Generates an input of `size` (clamped to what the day supports). The same seed always gives the same input.
+    pub fn generate(&self, size: usize, seed: u64) -> Synthetic {
+        let mut rng = Rng::new(seed);
+
+        (self.generate)(&mut rng, size.clamp(1, self.max_size))
+    }
+}
+
+/// Every year's generators
+pub static ALL: &[(u16, &[Generator])] = &[(2020, y2020::ALL)];
+
+pub fn get(year: u16, day: u8) -> Option<&'static Generator> {
+    ALL.iter()
+        .filter(|&&(generators_year, _)| generators_year == year)
+        .flat_map(|(_, generators)| generators.iter())
+        .find(|generator| generator.day == day)
+}
+
+/// A small xorshift generator. Synthetic inputs only need to look random and be reproducible from a seed.
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        // Run the seed through a splitmix step, so that small seeds still give well mixed states and 0 isn't stuck
+        let mut state = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        state = (state ^ (state >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        state = (state ^ (state >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+
+        Rng {
+            state: (state ^ (state >> 31)) | 1,
+        }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+
+    /// A number in `range`, which must not be empty
+    pub fn range(&mut self, range: Range<u64>) -> u64 {
+        range.start + self.next_u64() % (range.end - range.start)
+    }
+
+    /// True `percent` percent of the time
+    pub fn chance(&mut self, percent: u64) -> bool {
+        self.range(0..100) < percent
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0..items.len() as u64) as usize]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.range(0..i as u64 + 1) as usize);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::Part;
+    use crate::years;
+
+    #[test]
+    fn deterministic() {
+        let generator = get(2020, 8).expect("Day 8 has a generator");
+
+        assert_eq!(generator.generate(50, 7), generator.generate(50, 7));
+        assert_ne!(generator.generate(50, 7), generator.generate(50, 8));
+    }
+
+    #[test]
+    fn answers_match_solvers() {
+        for &(year, generators) in ALL {
+            for generator in generators {
+                let entry = years::get(year)
+                    .and_then(|year| year.get(generator.day))
+                    .expect("Every generator has a day");
+
+                for seed in 0..5 {
+                    let synthetic = generator.generate(40, seed);
+
+                    assert_eq!(
+                        entry.run(&synthetic.input, Part::One),
+                        Ok(synthetic.part1),
+                        "{} day {} part 1, seed {}",
+                        year,
+                        generator.day,
+                        seed
+                    );
+
+                    // 2020 day 15 part 2 plays 30 million turns, which is far too slow in a debug build
+                    if (year, generator.day) != (2020, 15) {
+                        assert_eq!(
+                            entry.run(&synthetic.input, Part::Two),
+                            Ok(synthetic.part2),
+                            "{} day {} part 2, seed {}",
+                            year,
+                            generator.day,
+                            seed
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
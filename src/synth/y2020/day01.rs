@@ -0,0 +1,57 @@
+use crate::synth::{Rng, Synthetic};
+
+const TARGET: u64 = 2020;
+
+/// Whether exactly the planted pair and triple sum to the target, allowing numbers to be reused like the solver does
+fn unambiguous(pair: [u64; 2], triple: [u64; 3]) -> bool {
+    let small: &[u64] = &[pair[0], pair[1], triple[0], triple[1], triple[2]];
+
+    let pairs = small
+        .iter()
+        .flat_map(|x| small.iter().map(move |y| x + y))
+        .filter(|&sum| sum == TARGET)
+        .count();
+    let triples = small
+        .iter()
+        .flat_map(|x| {
+            small
+                .iter()
+                .flat_map(move |y| small.iter().map(move |z| x + y + z))
+        })
+        .filter(|&sum| sum == TARGET)
+        .count();
+
+    // The solver checks both orders of the pair and all six orders of the triple
+    pairs == 2 && triples == 6
+}
+
+/// `size` expense report entries. One pair and one triple of small entries sum to 2020, everything else is too big to
+/// be part of either.
+pub fn generate(rng: &mut Rng, size: usize) -> Synthetic {
+    let (pair, triple) = loop {
+        let a = rng.range(1..TARGET);
+        let c = rng.range(1..TARGET - 1);
+        let d = rng.range(1..TARGET - c);
+
+        let pair = [a, TARGET - a];
+        let triple = [c, d, TARGET - c - d];
+
+        if unambiguous(pair, triple) {
+            break (pair, triple);
+        }
+    };
+
+    let mut entries: Vec<u64> = pair.iter().chain(&triple).copied().collect();
+    while entries.len() < size.max(5) {
+        entries.push(rng.range(TARGET + 1..100_000));
+    }
+    rng.shuffle(&mut entries);
+
+    let lines: Vec<String> = entries.iter().map(u64::to_string).collect();
+
+    Synthetic {
+        input: lines.join("\n"),
+        part1: (pair[0] * pair[1]).into(),
+        part2: (triple[0] * triple[1] * triple[2]).into(),
+    }
+}
@@ -0,0 +1,57 @@
+use crate::synth::{Rng, Synthetic};
+
+const LETTERS: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+
+/// `size` password policies. Each password is built around whether its policy letter sits at the two positions and
+/// how often it appears overall, so both answers are known without checking the passwords afterwards.
+pub fn generate(rng: &mut Rng, size: usize) -> Synthetic {
+    let mut lines = Vec::new();
+    let mut valid1 = 0;
+    let mut valid2 = 0;
+
+    for _ in 0..size {
+        let min = rng.range(1..10) as usize;
+        let max = rng.range(min as u64 + 1..20) as usize;
+        let len = rng.range(max as u64..max as u64 + 10) as usize;
+        let letter = *rng.pick(LETTERS);
+
+        // Decide which of the two positions hold the letter, then how many more copies go elsewhere
+        let at_min = rng.chance(50);
+        let at_max = rng.chance(50);
+        let fixed = at_min as usize + at_max as usize;
+        let count = fixed + rng.range(0..(len - 2) as u64 + 1) as usize;
+
+        let mut others: Vec<bool> = (0..len - 2).map(|i| i < count - fixed).collect();
+        rng.shuffle(&mut others);
+        let mut others = others.into_iter();
+
+        let password: String = (1..=len)
+            .map(|position| {
+                let is_letter = match position {
+                    p if p == min => at_min,
+                    p if p == max => at_max,
+                    _ => others.next().expect("One flag per remaining position"),
+                };
+
+                if is_letter {
+                    letter as char
+                } else {
+                    // Any letter but the policy's
+                    let other = rng.range(1..LETTERS.len() as u64) as u8;
+                    (b'a' + (letter - b'a' + other) % 26) as char
+                }
+            })
+            .collect();
+
+        valid1 += (min..=max).contains(&count) as u32;
+        valid2 += (at_min != at_max) as u32;
+
+        lines.push(format!("{}-{} {}: {}", min, max, letter as char, password));
+    }
+
+    Synthetic {
+        input: lines.join("\n"),
+        part1: valid1.into(),
+        part2: valid2.into(),
+    }
+}
@@ -0,0 +1,51 @@
+use crate::synth::{Rng, Synthetic};
+
+const WIDTH: usize = 31;
+const SLOPES: [(usize, usize); 5] = [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)];
+
+/// With at most this many trees on each slope, the product of all five still fits in the solver's u32
+const MAX_TREES: u32 = 80;
+
+/// A map `size` rows tall. Trees are scattered at random, except along the five slopes the puzzle asks about, where
+/// they're placed deliberately and counted as they go in.
+pub fn generate(rng: &mut Rng, size: usize) -> Synthetic {
+    let mut trees = [0; SLOPES.len()];
+    let mut rows = Vec::new();
+
+    for y in 0..size {
+        let row: String = (0..WIDTH)
+            .map(|x| {
+                let crossing: Vec<usize> = SLOPES
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, (right, down))| {
+                        // The toboggan starts at the top left, so that square is never counted
+                        y > 0 && y % down == 0 && (y / down * right) % WIDTH == x
+                    })
+                    .map(|(i, _)| i)
+                    .collect();
+
+                let tree = if crossing.iter().all(|&i| trees[i] < MAX_TREES) {
+                    rng.chance(20)
+                } else {
+                    false
+                };
+
+                if tree {
+                    crossing.iter().for_each(|&i| trees[i] += 1);
+                    '#'
+                } else {
+                    '.'
+                }
+            })
+            .collect();
+
+        rows.push(row);
+    }
+
+    Synthetic {
+        input: rows.join("\n"),
+        part1: trees[1].into(),
+        part2: trees.iter().product::<u32>().into(),
+    }
+}
@@ -0,0 +1,94 @@
+use crate::synth::{Rng, Synthetic};
+
+const REQUIRED: [&str; 7] = ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"];
+const EYE_COLORS: [&str; 7] = ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"];
+const HEX: &[u8] = b"0123456789abcdef";
+
+fn digits(rng: &mut Rng, count: usize) -> String {
+    (0..count)
+        .map(|_| (b'0' + rng.range(0..10) as u8) as char)
+        .collect()
+}
+
+fn valid_value(rng: &mut Rng, key: &str) -> String {
+    match key {
+        "byr" => rng.range(1920..2003).to_string(),
+        "iyr" => rng.range(2010..2021).to_string(),
+        "eyr" => rng.range(2020..2031).to_string(),
+        "hgt" if rng.chance(50) => format!("{}cm", rng.range(150..194)),
+        "hgt" => format!("{}in", rng.range(59..77)),
+        "hcl" => {
+            let color: String = (0..6).map(|_| *rng.pick(HEX) as char).collect();
+            format!("#{}", color)
+        }
+        "ecl" => rng.pick(&EYE_COLORS).to_string(),
+        "pid" => digits(rng, 9),
+        _ => rng.range(1..1000).to_string(),
+    }
+}
+
+fn invalid_value(rng: &mut Rng, key: &str) -> String {
+    match key {
+        "byr" => rng.range(2003..2100).to_string(),
+        "iyr" => rng.range(1900..2010).to_string(),
+        "eyr" => rng.range(2031..2100).to_string(),
+        "hgt" if rng.chance(50) => format!("{}in", rng.range(77..200)),
+        "hgt" => rng.range(150..194).to_string(),
+        "hcl" => digits(rng, 6),
+        "ecl" => "xyz".to_owned(),
+        _ => digits(rng, 10),
+    }
+}
+
+/// `size` passports, each built to be missing a field, to have every field but one of them invalid, or to be valid.
+pub fn generate(rng: &mut Rng, size: usize) -> Synthetic {
+    let mut passports = Vec::new();
+    let mut complete = 0;
+    let mut valid = 0;
+
+    for _ in 0..size {
+        let mut keys: Vec<&str> = REQUIRED.to_vec();
+        if rng.chance(50) {
+            keys.push("cid");
+        }
+
+        let missing = rng.chance(30);
+        let broken = !missing && rng.chance(40);
+
+        if missing {
+            keys.remove(rng.range(0..REQUIRED.len() as u64) as usize);
+        }
+        rng.shuffle(&mut keys);
+
+        let broken_key = *rng.pick(&REQUIRED);
+        let mut fields: Vec<String> = keys
+            .iter()
+            .map(|&key| {
+                let value = if broken && key == broken_key {
+                    invalid_value(rng, key)
+                } else {
+                    valid_value(rng, key)
+                };
+
+                format!("{}:{}", key, value)
+            })
+            .collect();
+
+        // Spread the fields over a few lines, the same way the puzzle does
+        let mut lines = Vec::new();
+        while !fields.is_empty() {
+            let take = rng.range(1..fields.len() as u64 + 1) as usize;
+            lines.push(fields.drain(..take).collect::<Vec<String>>().join(" "));
+        }
+
+        complete += !missing as u32;
+        valid += (!missing && !broken) as u32;
+        passports.push(lines.join("\n"));
+    }
+
+    Synthetic {
+        input: passports.join("\n\n"),
+        part1: complete.into(),
+        part2: valid.into(),
+    }
+}
@@ -0,0 +1,38 @@
+use crate::synth::{Rng, Synthetic};
+
+/// The boarding pass for a seat id, as its row and column partitions
+fn boarding_pass(id: u16) -> String {
+    let row = (0..7).rev().map(|bit| {
+        if (id >> 3) & (1 << bit) != 0 {
+            'B'
+        } else {
+            'F'
+        }
+    });
+    let column = (0..3)
+        .rev()
+        .map(|bit| if id & (1 << bit) != 0 { 'R' } else { 'L' });
+
+    row.chain(column).collect()
+}
+
+/// `size` boarding passes for a contiguous block of seats with one seat missing from the middle.
+pub fn generate(rng: &mut Rng, size: usize) -> Synthetic {
+    // Ids run from 0 to 1023, and the block needs room for the gap
+    let size = size.max(2) as u64;
+    let first = rng.range(0..1024 - size) as u16;
+    let last = first + size as u16;
+    let missing = rng.range(first as u64 + 1..last as u64) as u16;
+
+    let mut passes: Vec<String> = (first..=last)
+        .filter(|&id| id != missing)
+        .map(boarding_pass)
+        .collect();
+    rng.shuffle(&mut passes);
+
+    Synthetic {
+        input: passes.join("\n"),
+        part1: last.into(),
+        part2: missing.into(),
+    }
+}
@@ -0,0 +1,54 @@
+use crate::synth::{Rng, Synthetic};
+
+/// `size` groups. Each group has a set of questions everyone answered, and each person adds a few of their own that
+/// at least one other person in the group didn't answer.
+pub fn generate(rng: &mut Rng, size: usize) -> Synthetic {
+    let mut groups = Vec::new();
+    let mut anyone = 0;
+    let mut everyone = 0;
+
+    for _ in 0..size {
+        let mut questions: Vec<char> = ('a'..='z').collect();
+        rng.shuffle(&mut questions);
+
+        let people = rng.range(1..6) as usize;
+        // Everyone answers at least one question, since an empty line would end the group
+        let shared = rng.range(1..6) as usize;
+        let (common, extras) = questions.split_at(shared);
+
+        // A lone person answered everything they answered, so they get no extras
+        let mut answers: Vec<Vec<char>> = (0..people).map(|_| common.to_vec()).collect();
+        if people > 1 {
+            for &question in &extras[..rng.range(0..10) as usize] {
+                // Hand each extra question to some but not all of the group
+                let count = rng.range(1..people as u64) as usize;
+                let mut chosen: Vec<usize> = (0..people).collect();
+                rng.shuffle(&mut chosen);
+
+                chosen[..count]
+                    .iter()
+                    .for_each(|&person| answers[person].push(question));
+                anyone += 1;
+            }
+        }
+
+        anyone += shared;
+        everyone += shared;
+
+        let lines: Vec<String> = answers
+            .iter_mut()
+            .map(|answers| {
+                rng.shuffle(answers);
+                answers.iter().collect()
+            })
+            .collect();
+
+        groups.push(lines.join("\n"));
+    }
+
+    Synthetic {
+        input: groups.join("\n\n"),
+        part1: anyone.into(),
+        part2: everyone.into(),
+    }
+}
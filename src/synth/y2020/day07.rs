@@ -0,0 +1,122 @@
+use std::collections::HashMap;
+
+use crate::synth::{Rng, Synthetic};
+
+const TARGET: &str = "shiny gold";
+const LAYERS: usize = 6;
+
+const ADJECTIVES: [&str; 24] = [
+    "bright", "clear", "dark", "dim", "dotted", "drab", "dull", "faded", "light", "mirrored",
+    "muted", "pale", "plaid", "posh", "striped", "vibrant", "wavy", "shiny", "dusky", "glossy",
+    "matte", "mottled", "frosted", "soft",
+];
+const COLORS: [&str; 24] = [
+    "red", "orange", "yellow", "green", "blue", "indigo", "violet", "white", "black", "gray",
+    "brown", "tan", "teal", "cyan", "magenta", "maroon", "olive", "lime", "aqua", "coral", "beige",
+    "plum", "gold", "silver",
+];
+
+/// Bags that can end up holding `bag`, directly or not
+fn holders(bag: usize, contents: &[Vec<(usize, u64)>]) -> usize {
+    let mut found = vec![false; contents.len()];
+    let mut queue = vec![bag];
+
+    while let Some(inner) = queue.pop() {
+        for (outer, held) in contents.iter().enumerate() {
+            if !found[outer] && held.iter().any(|&(bag, _)| bag == inner) {
+                found[outer] = true;
+                queue.push(outer);
+            }
+        }
+    }
+
+    found.iter().filter(|&&found| found).count()
+}
+
+/// How many bags `bag` holds in total
+fn held(bag: usize, contents: &[Vec<(usize, u64)>], cache: &mut HashMap<usize, u64>) -> u64 {
+    if let Some(&count) = cache.get(&bag) {
+        return count;
+    }
+
+    let count = contents[bag]
+        .iter()
+        .map(|&(inner, count)| count * (1 + held(inner, contents, cache)))
+        .sum();
+    cache.insert(bag, count);
+
+    count
+}
+
+/// Rules for `size` bags in layers, where bags only ever hold bags from lower layers so the rules can't loop. Shiny
+/// gold sits in the second layer from the bottom, keeping the number of bags inside it small.
+pub fn generate(rng: &mut Rng, size: usize) -> Synthetic {
+    let size = size.max(LAYERS);
+
+    let mut names: Vec<String> = ADJECTIVES
+        .iter()
+        .flat_map(|adjective| {
+            COLORS
+                .iter()
+                .map(move |color| format!("{} {}", adjective, color))
+        })
+        .filter(|name| name != TARGET)
+        .collect();
+    rng.shuffle(&mut names);
+    names.truncate(size - 1);
+
+    // Shiny gold is always the first bag of layer 1
+    let layer_size = size / LAYERS;
+    let target = layer_size;
+    names.insert(target, TARGET.to_owned());
+
+    let layer = |bag: usize| (bag / layer_size.max(1)).min(LAYERS - 1);
+
+    let contents: Vec<Vec<(usize, u64)>> = (0..size)
+        .map(|bag| {
+            if layer(bag) == 0 {
+                return Vec::new();
+            }
+
+            // Hold one to three different bags from the layer directly below
+            let below = (layer(bag) - 1) * layer_size..layer(bag) * layer_size;
+            let mut held: Vec<usize> = (0..rng.range(1..4))
+                .map(|_| rng.range(below.start as u64..below.end as u64) as usize)
+                .collect();
+            held.sort_unstable();
+            held.dedup();
+
+            held.into_iter()
+                .map(|inner| (inner, rng.range(1..5)))
+                .collect()
+        })
+        .collect();
+
+    let mut rules: Vec<String> = contents
+        .iter()
+        .enumerate()
+        .map(|(bag, held)| {
+            let held = if held.is_empty() {
+                "no other bags".to_owned()
+            } else {
+                let held: Vec<String> = held
+                    .iter()
+                    .map(|&(inner, count)| {
+                        let plural = if count == 1 { "" } else { "s" };
+                        format!("{} {} bag{}", count, names[inner], plural)
+                    })
+                    .collect();
+                held.join(", ")
+            };
+
+            format!("{} bags contain {}.", names[bag], held)
+        })
+        .collect();
+    rng.shuffle(&mut rules);
+
+    Synthetic {
+        input: rules.join("\n"),
+        part1: holders(target, &contents).into(),
+        part2: held(target, &contents, &mut HashMap::new()).into(),
+    }
+}
@@ -0,0 +1,56 @@
+use crate::synth::{Rng, Synthetic};
+
+fn acc(rng: &mut Rng) -> i64 {
+    rng.range(0..100) as i64 - 50
+}
+
+/// A boot program of roughly `size` instructions. Execution runs through a chain of `acc`s, `nop +0`s and forward
+/// `jmp`s over dead code, then hits a `jmp` back to the start. Flipping that one `jmp` is the only fix: every `nop +0`
+/// turns into a `jmp +0` that loops forever, every forward `jmp` lands in dead code that loops back to the start, and
+/// past the final `jmp` there's nothing but `acc`s.
+pub fn generate(rng: &mut Rng, size: usize) -> Synthetic {
+    let mut program: Vec<String> = Vec::new();
+    let mut executed = 0;
+
+    // Leave room for the tail after the loop
+    let body = size.max(2) * 3 / 4;
+
+    while program.len() < body {
+        match rng.range(0..10) {
+            0..=5 => {
+                let value = acc(rng);
+                executed += value;
+                program.push(format!("acc {:+}", value));
+            }
+            6..=7 => program.push("nop +0".to_owned()),
+            _ => {
+                // Skip over a few dead instructions that end by jumping back to the start
+                let dead = rng.range(1..5) as usize;
+                program.push(format!("jmp +{}", dead + 1));
+
+                for _ in 1..dead {
+                    program.push(format!("acc {:+}", acc(rng)));
+                }
+
+                let back = program.len() as i64;
+                program.push(format!("jmp {:+}", -back));
+            }
+        }
+    }
+
+    let back = program.len() as i64;
+    program.push(format!("jmp {:+}", -back));
+
+    let mut terminated = executed;
+    while program.len() < size.max(2) {
+        let value = acc(rng);
+        terminated += value;
+        program.push(format!("acc {:+}", value));
+    }
+
+    Synthetic {
+        input: program.join("\n"),
+        part1: executed.into(),
+        part2: terminated.into(),
+    }
+}
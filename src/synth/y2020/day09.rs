@@ -0,0 +1,84 @@
+use crate::synth::{Rng, Synthetic};
+
+const PREAMBLE: usize = 25;
+
+fn is_pair_sum(window: &[u64], n: u64) -> bool {
+    window
+        .iter()
+        .enumerate()
+        .any(|(i, a)| window[i + 1..].iter().any(|b| a + b == n))
+}
+
+/// Whether the planted range is the only run of two or more numbers summing to `target`
+fn unique_range(numbers: &[u64], target: u64, planted: (usize, usize)) -> bool {
+    (0..numbers.len()).all(|start| {
+        let mut sum = numbers[start];
+
+        numbers[start + 1..].iter().enumerate().all(|(i, n)| {
+            sum += n;
+            sum != target || (start, start + i + 2) == planted
+        })
+    })
+}
+
+/// `size` numbers after the preamble. Every number is the sum of two of the 25 before it, except one planted number
+/// which is instead the sum of a contiguous run of earlier numbers. Numbers roughly double every 25 places, which is
+/// why inputs can't grow much beyond the puzzle's own size.
+pub fn generate(rng: &mut Rng, size: usize) -> Synthetic {
+    loop {
+        let mut numbers: Vec<u64> = (0..PREAMBLE).map(|_| rng.range(1..100)).collect();
+        let invalid_at = PREAMBLE + rng.range(size as u64 / 2..size as u64 + 1) as usize;
+
+        while numbers.len() < PREAMBLE + size + 1 {
+            let window = &numbers[numbers.len() - PREAMBLE..];
+
+            if numbers.len() == invalid_at {
+                // Any run of at least two numbers will do, as long as it isn't also a pair sum
+                let start = rng.range(0..invalid_at as u64 - 2) as usize;
+                let end = rng.range(start as u64 + 2..invalid_at as u64 + 1) as usize;
+                let target: u64 = numbers[start..end].iter().sum();
+
+                if is_pair_sum(window, target) {
+                    break;
+                }
+
+                let range = &numbers[start..end];
+                let answer = range.iter().min().unwrap() + range.iter().max().unwrap();
+                numbers.push(target);
+
+                // Keep going past the invalid number, so it isn't simply the last one
+                while numbers.len() < PREAMBLE + size + 1 {
+                    let window = &numbers[numbers.len() - PREAMBLE..];
+                    let (a, b) = distinct_pair(rng, window);
+                    numbers.push(a + b);
+                }
+
+                // Later numbers could add up to the target too, which would make the answer ambiguous
+                if !unique_range(&numbers, target, (start, end)) {
+                    break;
+                }
+
+                let lines: Vec<String> = numbers.iter().map(u64::to_string).collect();
+                return Synthetic {
+                    input: lines.join("\n"),
+                    part1: target.into(),
+                    part2: answer.into(),
+                };
+            }
+
+            let (a, b) = distinct_pair(rng, window);
+            numbers.push(a + b);
+        }
+    }
+}
+
+/// Two numbers from different places in the window, favouring small ones to slow the growth down
+fn distinct_pair(rng: &mut Rng, window: &[u64]) -> (u64, u64) {
+    let mut sorted = window.to_vec();
+    sorted.sort_unstable();
+
+    let a = rng.range(0..4) as usize;
+    let b = rng.range(a as u64 + 1..6) as usize;
+
+    (sorted[a], sorted[b])
+}
@@ -0,0 +1,47 @@
+use crate::synth::{Rng, Synthetic};
+
+/// Arrangements of a run of 1 jolt differences of each length, where the run's two ends can't be removed
+const ARRANGEMENTS: [u128; 5] = [1, 1, 2, 4, 7];
+
+/// `size` adapters in runs of up to four 1 jolt steps separated by 3 jolt steps. Each run's arrangements are known,
+/// so part 2 is their product.
+pub fn generate(rng: &mut Rng, size: usize) -> Synthetic {
+    let mut adapters = Vec::new();
+    let mut joltage = 0;
+    let mut ones = 0;
+    let mut threes = 0;
+    let mut arrangements: u128 = 1;
+
+    while adapters.len() < size {
+        if !adapters.is_empty() {
+            joltage += 3;
+            threes += 1;
+            adapters.push(joltage);
+        }
+
+        // Fall back to single steps once the arrangements would no longer fit in a u128
+        let mut run = rng.range(1..5).min((size - adapters.len()) as u64) as usize;
+        arrangements = match arrangements.checked_mul(ARRANGEMENTS[run]) {
+            Some(arrangements) => arrangements,
+            None => {
+                run = run.min(1);
+                arrangements
+            }
+        };
+
+        for _ in 0..run {
+            joltage += 1;
+            ones += 1;
+            adapters.push(joltage);
+        }
+    }
+
+    rng.shuffle(&mut adapters);
+    let lines: Vec<String> = adapters.iter().map(u32::to_string).collect();
+
+    Synthetic {
+        input: lines.join("\n"),
+        part1: (ones * (threes + 1)).into(),
+        part2: arrangements.into(),
+    }
+}
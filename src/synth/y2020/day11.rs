@@ -0,0 +1,123 @@
+use crate::synth::{Rng, Synthetic};
+
+const WIDTH: usize = 90;
+/// Percentage of floor tiles. Packed layouts tend to flip between two states forever under the part 1 rules, while
+/// nearly every layout with this much floor settles.
+const FLOOR: u64 = 30;
+const DIRECTIONS: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+#[derive(Copy, Clone, PartialEq)]
+enum Cell {
+    Floor,
+    Empty,
+    Occupied,
+}
+
+/// Random layouts don't always settle under the part 1 rules, and the ones that do settle well within this
+const MAX_GENERATIONS: usize = 1000;
+
+/// Runs the seating rules until nothing changes, counting occupied seats. `visible` picks whether seats look past
+/// floor (part 2) or only at their neighbours (part 1), and `tolerance` is how many occupied seats make people leave.
+fn settle(mut cells: Vec<Vec<Cell>>, visible: bool, tolerance: usize) -> Option<usize> {
+    let rows = cells.len() as isize;
+
+    for _ in 0..MAX_GENERATIONS {
+        let seen = |cells: &[Vec<Cell>], row: usize, column: usize| {
+            DIRECTIONS
+                .iter()
+                .filter(|(dr, dc)| {
+                    let (mut r, mut c) = (row as isize + dr, column as isize + dc);
+
+                    while r >= 0 && r < rows && c >= 0 && c < WIDTH as isize {
+                        match cells[r as usize][c as usize] {
+                            Cell::Occupied => return true,
+                            Cell::Empty => return false,
+                            Cell::Floor if !visible => return false,
+                            Cell::Floor => (),
+                        }
+
+                        r += dr;
+                        c += dc;
+                    }
+
+                    false
+                })
+                .count()
+        };
+
+        let next: Vec<Vec<Cell>> = (0..cells.len())
+            .map(|row| {
+                (0..WIDTH)
+                    .map(|column| match cells[row][column] {
+                        Cell::Empty if seen(&cells, row, column) == 0 => Cell::Occupied,
+                        Cell::Occupied if seen(&cells, row, column) >= tolerance => Cell::Empty,
+                        cell => cell,
+                    })
+                    .collect()
+            })
+            .collect();
+
+        if next == cells {
+            return Some(
+                cells
+                    .iter()
+                    .flatten()
+                    .filter(|&&cell| cell == Cell::Occupied)
+                    .count(),
+            );
+        }
+
+        cells = next;
+    }
+
+    None
+}
+
+/// A seat layout `size` rows tall and 90 seats wide, like the puzzle's own. Unlike most days the answers can't be
+/// built in up front, so they come from a plain simulation of the rules, rerolling layouts that never settle.
+pub fn generate(rng: &mut Rng, size: usize) -> Synthetic {
+    loop {
+        let cells: Vec<Vec<Cell>> = (0..size)
+            .map(|_| {
+                (0..WIDTH)
+                    .map(|_| {
+                        if rng.chance(FLOOR) {
+                            Cell::Floor
+                        } else {
+                            Cell::Empty
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let part1 = settle(cells.clone(), false, 4);
+        let part2 = settle(cells.clone(), true, 5);
+
+        if let (Some(part1), Some(part2)) = (part1, part2) {
+            let lines: Vec<String> = cells
+                .iter()
+                .map(|row| {
+                    row.iter()
+                        .map(|&cell| if cell == Cell::Floor { '.' } else { 'L' })
+                        .collect()
+                })
+                .collect();
+
+            return Synthetic {
+                input: lines.join("\n"),
+                part1: part1.into(),
+                part2: part2.into(),
+            };
+        }
+    }
+}
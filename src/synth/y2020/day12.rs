@@ -0,0 +1,99 @@
+use crate::synth::{Rng, Synthetic};
+
+/// Keeps every coordinate well inside an i32, let alone the solver's i64s
+const LIMIT: i64 = 100_000_000;
+
+/// Turns `(x, y)` a quarter turn anticlockwise `turns` times
+fn rotate((x, y): (i64, i64), turns: i64) -> (i64, i64) {
+    (0..turns.rem_euclid(4)).fold((x, y), |(x, y), _| (-y, x))
+}
+
+/// `size` navigation instructions, with both the ship and the waypoint tracked as they're generated. Any instruction
+/// that would move something too far out is swapped for a 180 degree turn, which never moves anything.
+pub fn generate(rng: &mut Rng, size: usize) -> Synthetic {
+    let mut lines = Vec::new();
+
+    // Part 1's ship and heading, as a unit vector
+    let mut ship = (0, 0);
+    let mut heading = (1, 0);
+
+    // Part 2's ship and waypoint
+    let mut ship2 = (0, 0);
+    let mut waypoint = (10, 1);
+
+    for _ in 0..size {
+        let action = *rng.pick(&['N', 'S', 'E', 'W', 'L', 'R', 'F', 'F']);
+        let value = match action {
+            'L' | 'R' => rng.range(1..4) as i64 * 90,
+            _ => rng.range(1..100) as i64,
+        };
+
+        let step = |(x, y): (i64, i64), (dx, dy): (i64, i64), by: i64| (x + dx * by, y + dy * by);
+        let (next, next_heading, next2, next_waypoint) = match action {
+            'N' => (
+                step(ship, (0, 1), value),
+                heading,
+                ship2,
+                step(waypoint, (0, 1), value),
+            ),
+            'S' => (
+                step(ship, (0, -1), value),
+                heading,
+                ship2,
+                step(waypoint, (0, -1), value),
+            ),
+            'E' => (
+                step(ship, (1, 0), value),
+                heading,
+                ship2,
+                step(waypoint, (1, 0), value),
+            ),
+            'W' => (
+                step(ship, (-1, 0), value),
+                heading,
+                ship2,
+                step(waypoint, (-1, 0), value),
+            ),
+            'L' => (
+                ship,
+                rotate(heading, value / 90),
+                ship2,
+                rotate(waypoint, value / 90),
+            ),
+            'R' => (
+                ship,
+                rotate(heading, -value / 90),
+                ship2,
+                rotate(waypoint, -value / 90),
+            ),
+            _ => (
+                step(ship, heading, value),
+                heading,
+                step(ship2, waypoint, value),
+                waypoint,
+            ),
+        };
+
+        let fits = [next, next2, next_waypoint]
+            .iter()
+            .all(|(x, y)| x.abs() + y.abs() < LIMIT);
+
+        if fits {
+            ship = next;
+            heading = next_heading;
+            ship2 = next2;
+            waypoint = next_waypoint;
+            lines.push(format!("{}{}", action, value));
+        } else {
+            heading = rotate(heading, 2);
+            waypoint = rotate(waypoint, 2);
+            lines.push("L180".to_owned());
+        }
+    }
+
+    Synthetic {
+        input: lines.join("\n"),
+        part1: (ship.0.abs() + ship.1.abs()).into(),
+        part2: (ship2.0.abs() + ship2.1.abs()).into(),
+    }
+}
@@ -0,0 +1,46 @@
+use crate::synth::{Rng, Synthetic};
+
+const PRIMES: [u64; 25] = [
+    2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97,
+];
+
+/// A schedule of `size` buses with distinct prime ids. Part 2's timestamp is picked first, and each bus is then placed
+/// at an offset that makes it depart at the right time. Nine buses is the most whose ids multiply to fit in a u64.
+pub fn generate(rng: &mut Rng, size: usize) -> Synthetic {
+    let mut ids = PRIMES.to_vec();
+    rng.shuffle(&mut ids);
+    ids.truncate(size);
+
+    let period: u64 = ids.iter().product();
+    let timestamp = rng.range(0..period);
+
+    // Each bus needs an offset congruent to -timestamp, past the previous bus
+    let mut schedule: Vec<Option<u64>> = Vec::new();
+    for &id in &ids {
+        let wanted = (id - timestamp % id) % id;
+        let mut offset = schedule.len() as u64;
+        offset += (wanted + id - offset % id) % id;
+
+        schedule.resize(offset as usize, None);
+        schedule.push(Some(id));
+    }
+
+    let earliest = rng.range(1000..1_000_000);
+    let (id, wait) = schedule
+        .iter()
+        .flatten()
+        .map(|&id| (id, (id - earliest % id) % id))
+        .min_by_key(|&(_, wait)| wait)
+        .expect("At least one bus");
+
+    let schedule: Vec<String> = schedule
+        .iter()
+        .map(|bus| bus.map_or("x".to_owned(), |id| id.to_string()))
+        .collect();
+
+    Synthetic {
+        input: format!("{}\n{}", earliest, schedule.join(",")),
+        part1: (id * wait).into(),
+        part2: timestamp.into(),
+    }
+}
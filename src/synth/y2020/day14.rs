@@ -0,0 +1,65 @@
+use std::collections::HashMap;
+
+use crate::synth::{Rng, Synthetic};
+
+/// `size` lines of masks and writes, with memory kept for both versions of the decoder as they're generated. Floating
+/// bits only go in the low byte so that writes overlap, and there are never more than four of them.
+pub fn generate(rng: &mut Rng, size: usize) -> Synthetic {
+    let mut lines = Vec::new();
+    let mut values = HashMap::new();
+    let mut addresses = HashMap::new();
+
+    while lines.len() < size {
+        let mut floating = 0;
+        let mask: Vec<char> = (0..36)
+            .map(|i| match rng.range(0..10) {
+                0..=1 if i >= 28 && floating < 4 => {
+                    floating += 1;
+                    'X'
+                }
+                0..=5 => '0',
+                _ => '1',
+            })
+            .collect();
+        lines.push(format!("mask = {}", mask.iter().collect::<String>()));
+
+        for _ in 0..rng.range(1..6) {
+            let address = rng.range(0..256);
+            let value = rng.range(0..1 << 36);
+
+            // Version 1 masks the value
+            let masked = mask
+                .iter()
+                .enumerate()
+                .fold(value, |value, (i, bit)| match bit {
+                    '0' => value & !(1 << (35 - i)),
+                    '1' => value | (1 << (35 - i)),
+                    _ => value,
+                });
+            values.insert(address, masked);
+
+            // Version 2 masks the address, with every X taking both values
+            let mut decoded = vec![0];
+            for (i, bit) in mask.iter().enumerate() {
+                let place = 1 << (35 - i);
+
+                decoded = match bit {
+                    '0' => decoded.iter().map(|a| a | (address & place)).collect(),
+                    '1' => decoded.iter().map(|a| a | place).collect(),
+                    _ => decoded.iter().flat_map(|a| vec![*a, a | place]).collect(),
+                };
+            }
+            decoded.into_iter().for_each(|address| {
+                addresses.insert(address, value);
+            });
+
+            lines.push(format!("mem[{}] = {}", address, value));
+        }
+    }
+
+    Synthetic {
+        input: lines.join("\n"),
+        part1: values.values().sum::<u64>().into(),
+        part2: addresses.values().sum::<u64>().into(),
+    }
+}
@@ -0,0 +1,40 @@
+use crate::synth::{Rng, Synthetic};
+
+/// The number spoken on turn `turns`, tracking when each number was last spoken in a flat table
+fn spoken(starting: &[u32], turns: usize) -> u32 {
+    if turns <= starting.len() {
+        return starting[turns - 1];
+    }
+
+    // 0 means a number hasn't been spoken yet, otherwise it's the turn it was last spoken on
+    let mut last_spoken = vec![0u32; turns];
+    for (turn, &n) in starting.iter().enumerate() {
+        last_spoken[n as usize] = turn as u32 + 1;
+    }
+
+    let mut current = *starting.last().expect("At least one starting number");
+    for turn in starting.len() as u32..turns as u32 {
+        let previous = last_spoken[current as usize];
+        last_spoken[current as usize] = turn;
+
+        current = if previous == 0 { 0 } else { turn - previous };
+    }
+
+    current
+}
+
+/// `size` distinct starting numbers. There's no shortcut to where the game goes, so the answers come from playing it
+/// out, which takes a few hundred megabytes for part 2.
+pub fn generate(rng: &mut Rng, size: usize) -> Synthetic {
+    let mut starting: Vec<u32> = (0..size as u32 * 2 + 10).collect();
+    rng.shuffle(&mut starting);
+    starting.truncate(size);
+
+    let numbers: Vec<String> = starting.iter().map(u32::to_string).collect();
+
+    Synthetic {
+        input: numbers.join(","),
+        part1: spoken(&starting, 2020).into(),
+        part2: spoken(&starting, 30_000_000).into(),
+    }
+}
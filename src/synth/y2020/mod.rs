@@ -0,0 +1,40 @@
+use super::Generator;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+
+/// A generator for every day of 2020
+pub static ALL: &[Generator] = &[
+    Generator::new(1, 1_000_000, day01::generate),
+    Generator::new(2, 65_535, day02::generate),
+    Generator::new(3, 1_000_000, day03::generate),
+    Generator::new(4, 65_535, day04::generate),
+    Generator::new(5, 1022, day05::generate),
+    Generator::new(6, 1_000_000, day06::generate),
+    Generator::new(7, 576, day07::generate),
+    Generator::new(8, 1_000_000, day08::generate),
+    Generator::new(9, 500, day09::generate),
+    Generator::new(10, 500, day10::generate),
+    Generator::new(11, 1000, day11::generate),
+    Generator::new(12, 1_000_000, day12::generate),
+    Generator::new(13, 9, day13::generate),
+    Generator::new(14, 100_000, day14::generate),
+    Generator::new(15, 2000, day15::generate),
+];
+
+pub fn get(day: u8) -> Option<&'static Generator> {
+    ALL.iter().find(|generator| generator.day == day)
+}
@@ -0,0 +1,114 @@
+use std::cell::RefCell;
+use std::fmt;
+use std::io::Write;
+
+/// Where trace output goes, and which days write to it
+struct Tracer {
+    days: Vec<u8>,
+    sink: Box<dyn Write>,
+}
+
+thread_local! {
+    static TRACER: RefCell<Option<Tracer>> = const { RefCell::new(None) };
+}
+
+/// Sends the trace of every listed day subsequently run on this thread to `sink`, replacing any earlier setting.
+/// Tracing is off until this is called.
+pub fn enable(days: &[u8], sink: Box<dyn Write>) {
+    TRACER.with(|tracer| {
+        *tracer.borrow_mut() = Some(Tracer {
+            days: days.to_vec(),
+            sink,
+        })
+    });
+}
+
+/// Turns tracing back off, flushing whatever was written
+pub fn disable() {
+    TRACER.with(|tracer| {
+        if let Some(mut tracer) = tracer.borrow_mut().take() {
+            let _ = tracer.sink.flush();
+        }
+    });
+}
+
+/// Whether `day` is being traced on this thread. The `trace!` macro checks this before formatting anything, so
+/// untraced days only pay for the lookup.
+pub fn enabled(day: u8) -> bool {
+    TRACER.with(|tracer| {
+        tracer
+            .borrow()
+            .as_ref()
+            .is_some_and(|tracer| tracer.days.contains(&day))
+    })
+}
+
+/// Writes one line of `day`'s trace. Use `trace!` rather than calling this directly.
+pub fn write(day: u8, args: fmt::Arguments) {
+    TRACER.with(|tracer| {
+        if let Some(tracer) = tracer.borrow_mut().as_mut() {
+            // A trace that can't be written shouldn't stop the solver
+            let _ = writeln!(tracer.sink, "day {:02}: {}", day, args);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io;
+    use std::rc::Rc;
+
+    use crate::solution::Part;
+    use crate::years;
+
+    /// A sink the test can still read after handing it over
+    #[derive(Clone, Default)]
+    struct Shared(Rc<RefCell<Vec<u8>>>);
+
+    impl Write for Shared {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.borrow_mut().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl Shared {
+        fn lines(&self) -> Vec<String> {
+            String::from_utf8_lossy(&self.0.borrow())
+                .lines()
+                .map(str::to_owned)
+                .collect()
+        }
+    }
+
+    #[test]
+    fn only_enabled_days() {
+        let day08 = years::get(2020).and_then(|year| year.get(8)).unwrap();
+        let program = "nop +0\nacc +1\njmp +4\nacc +3\njmp -3\nacc -99\nacc +1\njmp -4\nacc +6\n";
+
+        let sink = Shared::default();
+        enable(&[8], Box::new(sink.clone()));
+
+        assert!(enabled(8));
+        assert!(!enabled(7));
+
+        day08.run(program, Part::One).unwrap();
+        crate::trace!(7, "never written");
+        disable();
+
+        let lines = sink.lines();
+        assert_eq!(lines[0], "day 08: pc 0: nop +0, accumulator 0");
+        assert_eq!(lines.len(), 8);
+        assert!(lines.iter().all(|line| line.starts_with("day 08: ")));
+
+        // Nothing more is written once tracing is off
+        day08.run(program, Part::One).unwrap();
+        assert!(!enabled(8));
+        assert_eq!(sink.lines().len(), 8);
+    }
+}
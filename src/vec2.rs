@@ -0,0 +1,319 @@
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A position or a displacement on the plane. Like text on a screen, x grows to the right (east) and y grows down
+/// (south), which is how grids are read and printed, so vectors order row by row.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Vec2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Vec2 {
+    pub const ZERO: Vec2 = Vec2::new(0, 0);
+
+    pub const fn new(x: i64, y: i64) -> Vec2 {
+        Vec2 { x, y }
+    }
+
+    pub fn scale(self, factor: i64) -> Vec2 {
+        Vec2::new(self.x * factor, self.y * factor)
+    }
+
+    /// `self + other`, or `None` if either coordinate overflows
+    pub fn checked_add(self, other: Vec2) -> Option<Vec2> {
+        Some(Vec2::new(
+            self.x.checked_add(other.x)?,
+            self.y.checked_add(other.y)?,
+        ))
+    }
+
+    /// `self * factor`, or `None` if either coordinate overflows
+    pub fn checked_scale(self, factor: i64) -> Option<Vec2> {
+        Some(Vec2::new(
+            self.x.checked_mul(factor)?,
+            self.y.checked_mul(factor)?,
+        ))
+    }
+
+    /// The distance to `other` moving only along the axes, or `None` if it's too far for an i64
+    pub fn manhattan(self, other: Vec2) -> Option<i64> {
+        let (dx, dy) = self.distances(other)?;
+        dx.checked_add(dy)
+    }
+
+    /// The distance to `other` moving diagonally as well, like a king on a chessboard, or `None` if it's too far
+    /// for an i64
+    pub fn chebyshev(self, other: Vec2) -> Option<i64> {
+        let (dx, dy) = self.distances(other)?;
+        Some(dx.max(dy))
+    }
+
+    fn distances(self, other: Vec2) -> Option<(i64, i64)> {
+        let dx = self.x.checked_sub(other.x)?.checked_abs()?;
+        let dy = self.y.checked_sub(other.y)?.checked_abs()?;
+        Some((dx, dy))
+    }
+
+    /// This vector turned `quarters` quarter turns about the origin, the way a ship facing along it would turn
+    pub fn turn(self, turn: Turn, quarters: i64) -> Vec2 {
+        // With y pointing down, turning right takes east (1, 0) to south (0, 1)
+        (0..Self::right_turns(turn, quarters)).fold(self, |v, _| Vec2::new(-v.y, v.x))
+    }
+
+    /// Like `turn`, but `None` if a coordinate is `i64::MIN` and has no opposite
+    pub fn checked_turn(self, turn: Turn, quarters: i64) -> Option<Vec2> {
+        (0..Self::right_turns(turn, quarters))
+            .try_fold(self, |v, _| Some(Vec2::new(v.y.checked_neg()?, v.x)))
+    }
+
+    /// How many quarter turns right add up to the same turn
+    fn right_turns(turn: Turn, quarters: i64) -> i64 {
+        match turn {
+            Turn::Right => quarters.rem_euclid(4),
+            Turn::Left => (-quarters.rem_euclid(4)).rem_euclid(4),
+        }
+    }
+
+    /// This point turned `quarters` quarter turns about `pivot`
+    pub fn turn_about(self, pivot: Vec2, turn: Turn, quarters: i64) -> Vec2 {
+        pivot + (self - pivot).turn(turn, quarters)
+    }
+}
+
+impl Ord for Vec2 {
+    fn cmp(&self, other: &Vec2) -> Ordering {
+        (self.y, self.x).cmp(&(other.y, other.x))
+    }
+}
+
+impl PartialOrd for Vec2 {
+    fn partial_cmp(&self, other: &Vec2) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Vec2;
+
+    fn add(self, other: Vec2) -> Vec2 {
+        Vec2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Vec2;
+
+    fn sub(self, other: Vec2) -> Vec2 {
+        Vec2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl AddAssign for Vec2 {
+    fn add_assign(&mut self, other: Vec2) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Vec2 {
+    fn sub_assign(&mut self, other: Vec2) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Vec2;
+
+    fn neg(self) -> Vec2 {
+        Vec2::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<i64> for Vec2 {
+    type Output = Vec2;
+
+    fn mul(self, factor: i64) -> Vec2 {
+        self.scale(factor)
+    }
+}
+
+impl fmt::Display for Vec2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Turn {
+    Left,
+    Right,
+}
+
+/// A compass direction, clockwise from north
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Heading {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Heading {
+    pub const ALL: [Heading; 4] = [Heading::North, Heading::East, Heading::South, Heading::West];
+
+    /// A single step this way
+    pub const fn step(self) -> Vec2 {
+        match self {
+            Heading::North => Vec2::new(0, -1),
+            Heading::East => Vec2::new(1, 0),
+            Heading::South => Vec2::new(0, 1),
+            Heading::West => Vec2::new(-1, 0),
+        }
+    }
+
+    pub fn turn(self, turn: Turn, quarters: i64) -> Heading {
+        let quarters = match turn {
+            Turn::Right => quarters,
+            Turn::Left => -quarters,
+        };
+
+        Heading::ALL[(self as i64 + quarters).rem_euclid(4) as usize]
+    }
+
+    /// The bearing in degrees clockwise from north
+    pub fn degrees(self) -> i64 {
+        self as i64 * 90
+    }
+
+    /// The heading at a bearing in degrees clockwise from north, if it's a whole number of quarter turns
+    pub fn from_degrees(degrees: i64) -> Option<Heading> {
+        if degrees % 90 != 0 {
+            return None;
+        }
+
+        Some(Heading::ALL[(degrees / 90).rem_euclid(4) as usize])
+    }
+}
+
+/// Headings written as the first letter of their compass point
+impl TryFrom<char> for Heading {
+    type Error = char;
+
+    fn try_from(c: char) -> Result<Heading, char> {
+        match c {
+            'N' => Ok(Heading::North),
+            'E' => Ok(Heading::East),
+            'S' => Ok(Heading::South),
+            'W' => Ok(Heading::West),
+            _ => Err(c),
+        }
+    }
+}
+
+impl From<Heading> for char {
+    fn from(heading: Heading) -> char {
+        match heading {
+            Heading::North => 'N',
+            Heading::East => 'E',
+            Heading::South => 'S',
+            Heading::West => 'W',
+        }
+    }
+}
+
+/// The heading a unit step points in
+impl TryFrom<Vec2> for Heading {
+    type Error = Vec2;
+
+    fn try_from(step: Vec2) -> Result<Heading, Vec2> {
+        Heading::ALL
+            .iter()
+            .copied()
+            .find(|heading| heading.step() == step)
+            .ok_or(step)
+    }
+}
+
+impl fmt::Display for Heading {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", char::from(*self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let a = Vec2::new(3, -4);
+        let b = Vec2::new(-1, 2);
+
+        assert_eq!(a + b, Vec2::new(2, -2));
+        assert_eq!(a - b, Vec2::new(4, -6));
+        assert_eq!(-a * 2, Vec2::new(-6, 8));
+        assert_eq!(a.manhattan(Vec2::ZERO), Some(7));
+        assert_eq!(a.manhattan(b), Some(10));
+        assert_eq!(a.chebyshev(b), Some(6));
+        assert!(Vec2::new(5, 0) < Vec2::new(0, 1));
+
+        let far = Vec2::new(i64::MAX, i64::MIN);
+        assert_eq!(a.checked_add(b), Some(a + b));
+        assert_eq!(far.checked_add(Vec2::new(1, 0)), None);
+        assert_eq!(a.checked_scale(-2), Some(a * -2));
+        assert_eq!(far.checked_scale(2), None);
+        assert_eq!(far.manhattan(Vec2::ZERO), None);
+        assert_eq!(Vec2::new(i64::MAX, 1).manhattan(Vec2::ZERO), None);
+        assert_eq!(far.chebyshev(Vec2::new(0, -1)), Some(i64::MAX));
+    }
+
+    #[test]
+    fn turns() {
+        let east = Heading::East.step();
+
+        assert_eq!(east.turn(Turn::Right, 1), Heading::South.step());
+        assert_eq!(east.turn(Turn::Left, 1), Heading::North.step());
+        assert_eq!(east.turn(Turn::Left, 3), east.turn(Turn::Right, 1));
+        assert_eq!(east.turn(Turn::Right, 6), Heading::West.step());
+
+        let pivot = Vec2::new(1, 1);
+        assert_eq!(
+            Vec2::new(3, 1).turn_about(pivot, Turn::Right, 1),
+            Vec2::new(1, 3)
+        );
+        assert_eq!(pivot.turn_about(pivot, Turn::Left, 1), pivot);
+
+        assert_eq!(
+            east.checked_turn(Turn::Left, 5),
+            Some(east.turn(Turn::Left, 5))
+        );
+        assert_eq!(Vec2::new(0, i64::MIN).checked_turn(Turn::Right, 1), None);
+        assert_eq!(Vec2::new(i64::MIN, 0).checked_turn(Turn::Left, 2), None);
+
+        for heading in &Heading::ALL {
+            for quarters in -5..5 {
+                assert_eq!(
+                    heading.turn(Turn::Right, quarters).step(),
+                    heading.step().turn(Turn::Right, quarters)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn compass() {
+        assert_eq!(Heading::try_from('W'), Ok(Heading::West));
+        assert_eq!(Heading::try_from('X'), Err('X'));
+        assert_eq!(Heading::North.to_string(), "N");
+
+        assert_eq!(Heading::from_degrees(-90), Some(Heading::West));
+        assert_eq!(Heading::from_degrees(450), Some(Heading::East));
+        assert_eq!(Heading::from_degrees(45), None);
+        assert_eq!(Heading::South.degrees(), 180);
+
+        assert_eq!(Heading::try_from(Vec2::new(0, -1)), Ok(Heading::North));
+        assert_eq!(Heading::try_from(Vec2::new(1, 1)), Err(Vec2::new(1, 1)));
+    }
+}
@@ -0,0 +1,423 @@
+use std::collections::{BTreeMap, HashSet};
+use std::convert::TryFrom;
+use std::fmt;
+
+use crate::budget::Budget;
+use crate::error::SolveError;
+
+/// The value held by a register or a memory cell
+pub type Word = i64;
+
+/// A machine that hasn't halted after this many instructions probably never will
+pub const MAX_STEPS: u64 = 10_000_000;
+
+/// Why a machine stopped with an error instead of halting
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fault {
+    /// The program counter left the program anywhere other than just past its end
+    JumpedOutside(Word),
+    /// An instruction read or wrote a register the instruction set doesn't have
+    InvalidRegister(usize),
+    /// An instruction read or wrote memory at a negative address
+    InvalidAddress(Word),
+    /// Arithmetic overflowed a `Word`
+    Overflow,
+    DivideByZero,
+    /// Anything else an instruction set considers an error
+    Other(String),
+}
+
+impl fmt::Display for Fault {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Fault::JumpedOutside(pc) => write!(f, "jumped outside the program to {}", pc),
+            Fault::InvalidRegister(register) => write!(f, "no register {}", register),
+            Fault::InvalidAddress(address) => write!(f, "invalid address {}", address),
+            Fault::Overflow => write!(f, "overflow"),
+            Fault::DivideByZero => write!(f, "divide by zero"),
+            Fault::Other(reason) => write!(f, "{}", reason),
+        }
+    }
+}
+
+/// How a run ended
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    /// The program counter moved just past the last instruction
+    Halted,
+    /// The machine was about to run the instruction at `pc` from a point it had already been at, as far as the
+    /// machine's `LoopCheck` can tell
+    Looped { pc: Word },
+    /// An instruction failed, or the program counter left the program
+    Faulted { pc: Word, fault: Fault },
+    /// A hook asked for the run to stop before running the instruction at `pc`
+    Stopped { pc: Word },
+}
+
+/// What a machine remembers to notice it's going round in circles
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LoopCheck {
+    /// Nothing: the run goes on until it halts, faults or runs out of steps
+    Off,
+    /// Which instructions have run. Only exact for programs whose jumps don't depend on registers or memory, since a
+    /// conditional jump can revisit an instruction without looping.
+    Pc,
+    /// The whole state before every instruction, which is always exact but costs a copy of memory per step
+    State,
+}
+
+/// Memory addressed from zero, where every cell holds zero until written
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Memory {
+    cells: BTreeMap<Word, Word>,
+}
+
+impl Memory {
+    pub fn read(&self, address: Word) -> Result<Word, Fault> {
+        if address < 0 {
+            return Err(Fault::InvalidAddress(address));
+        }
+
+        Ok(self.cells.get(&address).copied().unwrap_or_default())
+    }
+
+    pub fn write(&mut self, address: Word, value: Word) -> Result<(), Fault> {
+        if address < 0 {
+            return Err(Fault::InvalidAddress(address));
+        }
+
+        // Zero is what unwritten cells read as, so there's no need to keep it around
+        if value == 0 {
+            self.cells.remove(&address);
+        } else {
+            self.cells.insert(address, value);
+        }
+
+        Ok(())
+    }
+}
+
+/// Everything an instruction can see or change
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct State {
+    pub pc: Word,
+    registers: Vec<Word>,
+    pub memory: Memory,
+}
+
+impl State {
+    pub fn register(&self, register: usize) -> Result<Word, Fault> {
+        self.registers
+            .get(register)
+            .copied()
+            .ok_or(Fault::InvalidRegister(register))
+    }
+
+    pub fn set_register(&mut self, register: usize, value: Word) -> Result<(), Fault> {
+        let cell = self
+            .registers
+            .get_mut(register)
+            .ok_or(Fault::InvalidRegister(register))?;
+
+        *cell = value;
+        Ok(())
+    }
+
+    pub fn registers(&self) -> &[Word] {
+        &self.registers
+    }
+}
+
+/// Where the program counter goes once an instruction has run
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Flow {
+    /// On to the next instruction
+    Next,
+    /// This many instructions forwards, or backwards if negative
+    Jump(Word),
+    /// Straight to this instruction
+    Goto(Word),
+    /// Nowhere, the program is done
+    Halt,
+}
+
+/// One instruction of an instruction set. A puzzle's instruction set is usually an enum of its operations or a struct
+/// of an operation and its arguments, parsed from the puzzle input.
+pub trait Instruction: fmt::Display {
+    /// How many registers a machine running this instruction set has
+    const REGISTERS: usize;
+
+    fn execute(&self, state: &mut State) -> Result<Flow, Fault>;
+}
+
+/// What a hook tells the machine to do next
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Control {
+    Continue,
+    Stop,
+}
+
+/// Watches, and can stop, a machine as it runs. Closures taking the state and the instruction about to run are hooks
+/// that only look before each instruction.
+pub trait Hook<I> {
+    /// Called before `instruction` runs, stopping the run if this returns `Control::Stop`
+    fn before(&mut self, _state: &State, _instruction: &I) -> Control {
+        Control::Continue
+    }
+
+    /// Called after `instruction` has run and moved the program counter
+    fn after(&mut self, _state: &State, _instruction: &I) {}
+}
+
+impl<I, F: FnMut(&State, &I) -> Control> Hook<I> for F {
+    fn before(&mut self, state: &State, instruction: &I) -> Control {
+        self(state, instruction)
+    }
+}
+
+/// Runs a program of `I` instructions, starting with the program counter, every register and all of memory at zero.
+#[derive(Debug, Clone)]
+pub struct Machine<I> {
+    pub program: Vec<I>,
+    pub state: State,
+    loop_check: LoopCheck,
+    max_steps: u64,
+}
+
+impl<I: Instruction> Machine<I> {
+    pub fn new(program: Vec<I>) -> Machine<I> {
+        Machine {
+            program,
+            state: State {
+                pc: 0,
+                registers: vec![0; I::REGISTERS],
+                memory: Memory::default(),
+            },
+            loop_check: LoopCheck::Off,
+            max_steps: MAX_STEPS,
+        }
+    }
+
+    pub fn with_loop_check(mut self, loop_check: LoopCheck) -> Machine<I> {
+        self.loop_check = loop_check;
+        self
+    }
+
+    /// Replaces `MAX_STEPS` for this machine, unless the runner has set its own limit
+    pub fn with_max_steps(mut self, max_steps: u64) -> Machine<I> {
+        self.max_steps = max_steps;
+        self
+    }
+
+    /// Runs a single instruction, returning how the machine stopped if it did
+    pub fn step(&mut self) -> Option<Outcome> {
+        let instruction = match current(&self.program, self.state.pc) {
+            Ok(instruction) => instruction,
+            Err(outcome) => return Some(outcome),
+        };
+
+        let pc = self.state.pc;
+        let flow = match instruction.execute(&mut self.state) {
+            Ok(flow) => flow,
+            Err(fault) => return Some(Outcome::Faulted { pc, fault }),
+        };
+
+        let next = match flow {
+            Flow::Next => pc.checked_add(1),
+            Flow::Jump(offset) => pc.checked_add(offset),
+            Flow::Goto(target) => Some(target),
+            Flow::Halt => return Some(Outcome::Halted),
+        };
+
+        match next {
+            Some(next) => {
+                self.state.pc = next;
+                None
+            }
+            None => Some(Outcome::Faulted {
+                pc,
+                fault: Fault::Overflow,
+            }),
+        }
+    }
+
+    /// Runs until the program halts, faults or loops
+    pub fn run(&mut self) -> Result<Outcome, SolveError> {
+        self.run_with(&mut |_: &State, _: &I| Control::Continue)
+    }
+
+    /// Runs until the program halts, faults or loops, or `hook` stops it. Fails once the machine's step budget is
+    /// spent, so a program that loops without the loop check noticing can't hang the solver.
+    pub fn run_with<H: Hook<I>>(&mut self, hook: &mut H) -> Result<Outcome, SolveError> {
+        let mut budget = Budget::new(self.max_steps);
+        let mut pcs = HashSet::new();
+        let mut states = HashSet::new();
+
+        loop {
+            let instruction = match current(&self.program, self.state.pc) {
+                Ok(instruction) => instruction,
+                Err(outcome) => return Ok(outcome),
+            };
+
+            let pc = self.state.pc;
+            let repeated = match self.loop_check {
+                LoopCheck::Off => false,
+                LoopCheck::Pc => !pcs.insert(pc),
+                LoopCheck::State => !states.insert(self.state.clone()),
+            };
+            if repeated {
+                return Ok(Outcome::Looped { pc });
+            }
+
+            if hook.before(&self.state, instruction) == Control::Stop {
+                return Ok(Outcome::Stopped { pc });
+            }
+
+            budget.tick()?;
+            if let Some(outcome) = self.step() {
+                return Ok(outcome);
+            }
+
+            if let Ok(instruction) = current(&self.program, pc) {
+                hook.after(&self.state, instruction);
+            }
+        }
+    }
+}
+
+/// The instruction `pc` points at, or how the machine stops if it doesn't point at one
+fn current<I>(program: &[I], pc: Word) -> Result<&I, Outcome> {
+    match usize::try_from(pc).ok().and_then(|i| program.get(i)) {
+        Some(instruction) => Ok(instruction),
+        None if pc == program.len() as Word => Err(Outcome::Halted),
+        None => Err(Outcome::Faulted {
+            pc,
+            fault: Fault::JumpedOutside(pc),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A counter with a conditional jump, to tell the two loop checks apart
+    #[derive(Debug)]
+    enum Op {
+        /// Adds to register 0
+        Add(Word),
+        /// Stores register 0 at an address
+        Store(Word),
+        /// Jumps while register 0 is below a limit
+        JumpBelow(Word, Word),
+        Jump(Word),
+        Div(Word),
+    }
+
+    impl fmt::Display for Op {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "{:?}", self)
+        }
+    }
+
+    impl Instruction for Op {
+        const REGISTERS: usize = 1;
+
+        fn execute(&self, state: &mut State) -> Result<Flow, Fault> {
+            let value = state.register(0)?;
+
+            match *self {
+                Op::Add(n) => {
+                    state.set_register(0, value.checked_add(n).ok_or(Fault::Overflow)?)?
+                }
+                Op::Store(address) => state.memory.write(address, value)?,
+                Op::JumpBelow(limit, offset) if value < limit => return Ok(Flow::Jump(offset)),
+                Op::JumpBelow(..) => (),
+                Op::Jump(offset) => return Ok(Flow::Jump(offset)),
+                Op::Div(n) => {
+                    state.set_register(0, value.checked_div(n).ok_or(Fault::DivideByZero)?)?
+                }
+            }
+
+            Ok(Flow::Next)
+        }
+    }
+
+    #[test]
+    fn outcomes() {
+        let counter = || vec![Op::Add(1), Op::JumpBelow(5, -1), Op::Store(3)];
+
+        let mut machine = Machine::new(counter());
+        assert_eq!(machine.run(), Ok(Outcome::Halted));
+        assert_eq!(machine.state.registers(), &[5]);
+        assert_eq!(machine.state.memory.read(3), Ok(5));
+
+        // Counting revisits instructions without looping, which only the state check can tell
+        let mut machine = Machine::new(counter()).with_loop_check(LoopCheck::Pc);
+        assert_eq!(machine.run(), Ok(Outcome::Looped { pc: 0 }));
+        let mut machine = Machine::new(counter()).with_loop_check(LoopCheck::State);
+        assert_eq!(machine.run(), Ok(Outcome::Halted));
+
+        let mut machine = Machine::new(vec![Op::Jump(0)]).with_loop_check(LoopCheck::State);
+        assert_eq!(machine.run(), Ok(Outcome::Looped { pc: 0 }));
+
+        let mut machine = Machine::new(vec![Op::Jump(0)]).with_max_steps(10);
+        assert_eq!(machine.run(), Err(SolveError::DidNotConverge { steps: 10 }));
+
+        let mut machine = Machine::new(vec![Op::Div(0)]);
+        assert_eq!(
+            machine.run(),
+            Ok(Outcome::Faulted {
+                pc: 0,
+                fault: Fault::DivideByZero
+            })
+        );
+
+        let mut machine = Machine::new(vec![Op::Jump(-1)]);
+        assert_eq!(
+            machine.run(),
+            Ok(Outcome::Faulted {
+                pc: -1,
+                fault: Fault::JumpedOutside(-1)
+            })
+        );
+    }
+
+    #[test]
+    fn hooks() {
+        struct Counter {
+            before: usize,
+            after: usize,
+        }
+
+        impl Hook<Op> for Counter {
+            fn before(&mut self, _: &State, _: &Op) -> Control {
+                self.before += 1;
+                Control::Continue
+            }
+
+            fn after(&mut self, state: &State, _: &Op) {
+                self.after += 1;
+                assert!(state.pc <= 3);
+            }
+        }
+
+        let mut counter = Counter {
+            before: 0,
+            after: 0,
+        };
+        let mut machine = Machine::new(vec![Op::Add(1), Op::JumpBelow(5, -1), Op::Store(3)]);
+        assert_eq!(machine.run_with(&mut counter), Ok(Outcome::Halted));
+        assert_eq!((counter.before, counter.after), (11, 11));
+
+        let mut machine = Machine::new(vec![Op::Add(1), Op::JumpBelow(5, -1), Op::Store(3)]);
+        let stop = machine.run_with(&mut |state: &State, _: &Op| {
+            if state.register(0) == Ok(3) {
+                Control::Stop
+            } else {
+                Control::Continue
+            }
+        });
+        assert_eq!(stop, Ok(Outcome::Stopped { pc: 1 }));
+    }
+}
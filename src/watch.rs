@@ -0,0 +1,285 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::SystemTime;
+
+use crate::answer::Answer;
+use crate::cli::Outcome;
+use crate::input::InputSource;
+
+/// What a file looked like the last time it was scanned. The length catches edits made within the filesystem's
+/// timestamp resolution.
+type Stamp = (Option<SystemTime>, u64);
+
+/// Notices when any file under a set of paths is created, changed or removed. Directories are watched recursively, and
+/// paths that don't exist yet are watched for being created.
+pub struct Watcher {
+    paths: Vec<PathBuf>,
+    seen: BTreeMap<PathBuf, Stamp>,
+}
+
+impl Watcher {
+    /// Starts watching `paths`, taking their current state as the baseline
+    pub fn new(paths: Vec<PathBuf>) -> Watcher {
+        let seen = scan(&paths);
+
+        Watcher { paths, seen }
+    }
+
+    /// The files that have been created, changed or removed since the last call, in path order
+    pub fn changes(&mut self) -> Vec<PathBuf> {
+        let now = scan(&self.paths);
+
+        let mut changed: Vec<PathBuf> = now
+            .iter()
+            .filter(|(path, stamp)| self.seen.get(*path) != Some(stamp))
+            .map(|(path, _)| path.clone())
+            .chain(
+                self.seen
+                    .keys()
+                    .filter(|path| !now.contains_key(*path))
+                    .cloned(),
+            )
+            .collect();
+        changed.sort();
+
+        self.seen = now;
+        changed
+    }
+}
+
+fn scan(paths: &[PathBuf]) -> BTreeMap<PathBuf, Stamp> {
+    let mut stamps = BTreeMap::new();
+
+    for path in paths {
+        visit(path, &mut stamps);
+    }
+
+    stamps
+}
+
+fn visit(path: &Path, stamps: &mut BTreeMap<PathBuf, Stamp>) {
+    // Anything that vanishes mid-scan is simply picked up as removed on this scan or the next
+    let metadata = match fs::metadata(path) {
+        Ok(metadata) => metadata,
+        Err(_) => return,
+    };
+
+    if metadata.is_dir() {
+        if let Ok(entries) = fs::read_dir(path) {
+            for entry in entries.flatten() {
+                visit(&entry.path(), stamps);
+            }
+        }
+    } else {
+        stamps.insert(path.to_owned(), (metadata.modified().ok(), metadata.len()));
+    }
+}
+
+/// Everything a day's results depend on under the repository at `root`: its module, its fixtures and, if it comes from a
+/// file, its input
+pub fn paths(root: &Path, year: u16, day: u8, input: Option<PathBuf>) -> Vec<PathBuf> {
+    let mut paths = vec![
+        root.join("src")
+            .join("years")
+            .join(format!("y{}", year))
+            .join(format!("day{:02}.rs", day)),
+        root.join("tests")
+            .join("fixtures")
+            .join(year.to_string())
+            .join(format!("day{:02}", day)),
+    ];
+    paths.extend(input);
+
+    paths
+}
+
+/// Rebuilds and runs a day through cargo, so that changes to its source are picked up
+pub struct Cargo {
+    pub manifest: PathBuf,
+    pub release: bool,
+}
+
+impl Cargo {
+    fn command(&self, subcommand: &str) -> Command {
+        let mut command = Command::new("cargo");
+        command
+            .arg(subcommand)
+            .arg("--quiet")
+            .arg("--manifest-path")
+            .arg(&self.manifest);
+
+        if self.release {
+            command.arg("--release");
+        }
+
+        command
+    }
+
+    /// Runs the day's tests, which include its `aoc_test!` examples and fixtures. On failure, returns what cargo
+    /// printed.
+    pub fn examples(&self, year: u16, day: u8) -> Result<(), String> {
+        let output = self
+            .command("test")
+            .arg("--lib")
+            .arg(format!("years::y{}::day{:02}::", year, day))
+            .output()
+            .map_err(|e| format!("Unable to run cargo: {}", e))?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            Err(String::from_utf8_lossy(&output.stdout).into_owned()
+                + &String::from_utf8_lossy(&output.stderr))
+        }
+    }
+
+    /// Runs both parts of the day against its real input
+    pub fn answers(
+        &self,
+        year: u16,
+        day: u8,
+        source: &InputSource,
+    ) -> Result<Vec<Outcome>, String> {
+        let mut command = self.command("run");
+        command
+            .args(["--bin", "aoc", "--", "run", "--format", "json"])
+            .arg("--year")
+            .arg(year.to_string())
+            .arg(day.to_string());
+
+        match source {
+            InputSource::File(path) => command.arg("--input").arg(path),
+            InputSource::Dir(dir) => command.arg("--input-dir").arg(dir),
+            InputSource::Stdin => return Err("Watching needs an input file".to_owned()),
+        };
+
+        let output = command
+            .output()
+            .map_err(|e| format!("Unable to run cargo: {}", e))?;
+
+        // Failing parts still print their outcomes, so only a failed build leaves nothing to parse
+        serde_json::from_slice(&output.stdout)
+            .map_err(|_| String::from_utf8_lossy(&output.stderr).into_owned())
+    }
+}
+
+/// How one part's result compares to the previous run's
+#[derive(Debug, PartialEq)]
+pub struct Diff {
+    pub part: u8,
+    /// `None` on the first run, or if the part wasn't run last time
+    pub before: Option<Result<Answer, String>>,
+    pub after: Result<Answer, String>,
+}
+
+impl Diff {
+    pub fn changed(&self) -> bool {
+        self.before.as_ref() != Some(&self.after)
+    }
+}
+
+impl fmt::Display for Diff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fn show(result: &Result<Answer, String>) -> String {
+            match result {
+                Ok(answer) => answer.to_string(),
+                Err(e) => format!("error: {}", e),
+            }
+        }
+
+        write!(f, "Part {}: ", self.part)?;
+        match &self.before {
+            None => write!(f, "{}", show(&self.after)),
+            Some(before) if before == &self.after => write!(f, "{} (unchanged)", show(&self.after)),
+            Some(before) => write!(f, "{} -> {}", show(before), show(&self.after)),
+        }
+    }
+}
+
+/// Compares each part of `after` with the same part of `before`
+pub fn diff(before: &[Outcome], after: &[Outcome]) -> Vec<Diff> {
+    fn result(outcome: &Outcome) -> Result<Answer, String> {
+        match (&outcome.answer, &outcome.error) {
+            (Some(answer), _) => Ok(answer.clone()),
+            (None, error) => Err(error.clone().unwrap_or_default()),
+        }
+    }
+
+    after
+        .iter()
+        .map(|outcome| Diff {
+            part: outcome.part,
+            before: before
+                .iter()
+                .find(|previous| previous.day == outcome.day && previous.part == outcome.part)
+                .map(result),
+            after: result(outcome),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn notices_changes() {
+        let dir = tempfile::tempdir().unwrap();
+        let module = dir.path().join("day01.rs");
+        let fixtures = dir.path().join("fixtures");
+        let input = dir.path().join("day1.txt");
+        fs::write(&module, "fn main() {}").unwrap();
+        fs::create_dir(&fixtures).unwrap();
+
+        let mut watcher = Watcher::new(vec![module.clone(), fixtures.clone(), input.clone()]);
+        assert!(watcher.changes().is_empty());
+
+        fs::write(&module, "fn main() { 1; }").unwrap();
+        assert_eq!(watcher.changes(), vec![module.clone()]);
+        assert!(watcher.changes().is_empty());
+
+        // Files appearing inside a watched directory, and watched files that didn't exist yet
+        let fixture = fixtures.join("example.txt");
+        fs::write(&fixture, "1721").unwrap();
+        fs::write(&input, "1721").unwrap();
+        assert_eq!(watcher.changes(), vec![input.clone(), fixture.clone()]);
+
+        fs::remove_file(&fixture).unwrap();
+        assert_eq!(watcher.changes(), vec![fixture]);
+        assert!(watcher.changes().is_empty());
+    }
+
+    #[test]
+    fn diffs_answers() {
+        let outcome = |part, answer: Option<&str>, error: Option<&str>| Outcome {
+            day: 1,
+            part,
+            answer: answer.map(Answer::from),
+            error: error.map(str::to_owned),
+        };
+
+        let first = vec![
+            outcome(1, Some("514579"), None),
+            outcome(2, None, Some("No answer")),
+        ];
+        let diffs = diff(&[], &first);
+        assert!(diffs.iter().all(Diff::changed));
+        assert_eq!(diffs[0].to_string(), "Part 1: 514579");
+        assert_eq!(diffs[1].to_string(), "Part 2: error: No answer");
+
+        let second = vec![
+            outcome(1, Some("514579"), None),
+            outcome(2, Some("241861950"), None),
+        ];
+        let diffs = diff(&first, &second);
+        assert!(!diffs[0].changed());
+        assert_eq!(diffs[0].to_string(), "Part 1: 514579 (unchanged)");
+        assert_eq!(
+            diffs[1].to_string(),
+            "Part 2: error: No answer -> 241861950"
+        );
+    }
+}
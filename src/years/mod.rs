@@ -0,0 +1,51 @@
+use crate::solution::Entry;
+
+pub mod y2020;
+
+/// The year cargo-aoc's `aoc_lib!` is built for. It only knows about one year per crate, so only this year's days
+/// carry `#[aoc]` attributes; every year is available through the `aoc` binary.
+pub const RUNNER_YEAR: u16 = 2020;
+
+/// Every implemented day of one event
+pub struct Year {
+    pub year: u16,
+    pub days: &'static [Entry],
+}
+
+impl Year {
+    /// Looks up a day of this year by its number
+    pub fn get(&self, day: u8) -> Option<&'static Entry> {
+        self.days.iter().find(|entry| entry.day == day)
+    }
+}
+
+/// Every implemented year, in order
+pub static ALL: &[Year] = &[Year {
+    year: 2020,
+    days: y2020::ALL,
+}];
+
+/// Looks up a year in the registry
+pub fn get(year: u16) -> Option<&'static Year> {
+    ALL.iter().find(|entry| entry.year == year)
+}
+
+/// The most recent year, which the CLI runs when none is given
+pub fn latest() -> &'static Year {
+    ALL.last().expect("At least one year is registered")
+}
+
+/// The input of every `aoc_test!` example in every year, along with its year, day and name
+#[cfg(test)]
+pub(crate) fn examples() -> Vec<(u16, u8, &'static str, String)> {
+    let examples = vec![(2020, y2020::examples())];
+
+    examples
+        .into_iter()
+        .flat_map(|(year, examples)| {
+            examples
+                .into_iter()
+                .map(move |(day, name, input)| (year, day, name, input))
+        })
+        .collect()
+}
@@ -0,0 +1,110 @@
+use aoc_runner_derive::aoc;
+use aoc_runner_derive::aoc_generator;
+
+use crate::aoc_test;
+use crate::error::{check_lines, parse_lines, ParseError, SolveError};
+use crate::input::normalize;
+use crate::params::Param;
+use crate::solution::Solution;
+
+/// What the entries need to sum to
+const TARGET: Param<u32> = Param::new(1, "target", || 2020);
+
+#[aoc_generator(day1)]
+fn generator(input: &str) -> Result<Vec<u32>, ParseError> {
+    let input = normalize(input);
+
+    parse_lines(1, &input, parse_line)
+}
+
+fn parse_line(line: &str) -> Result<u32, ParseError> {
+    line.parse::<u32>()
+        .map_err(|_| ParseError::new("Unable to parse number", line))
+}
+
+#[aoc(day1, part1)]
+fn part1(input: &[u32]) -> Result<u32, SolveError> {
+    let target = TARGET.get()?;
+
+    for x in input {
+        for y in input {
+            // A sum too big for a u32 can't be the target either
+            if x.checked_add(*y) == Some(target) {
+                return x.checked_mul(*y).ok_or_else(|| overflow(&[*x, *y]));
+            }
+        }
+    }
+
+    Err(SolveError::NoAnswer(format!(
+        "No two entries sum to {}",
+        target
+    )))
+}
+
+#[aoc(day1, part2)]
+fn part2(input: &[u32]) -> Result<u32, SolveError> {
+    let target = TARGET.get()?;
+
+    for x in input {
+        for y in input {
+            for z in input {
+                if x.checked_add(*y).and_then(|sum| sum.checked_add(*z)) == Some(target) {
+                    return x
+                        .checked_mul(*y)
+                        .and_then(|product| product.checked_mul(*z))
+                        .ok_or_else(|| overflow(&[*x, *y, *z]));
+                }
+            }
+        }
+    }
+
+    Err(SolveError::NoAnswer(format!(
+        "No three entries sum to {}",
+        target
+    )))
+}
+
+fn overflow(entries: &[u32]) -> SolveError {
+    SolveError::NoAnswer(format!("The product of {:?} is too big for a u32", entries))
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const PARAMS: &'static [&'static str] = &[TARGET.name];
+
+    type Input = Vec<u32>;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        generator(input)
+    }
+
+    fn check(input: &str) -> Vec<ParseError> {
+        check_lines(1, &normalize(input), parse_line)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Output1, SolveError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Output2, SolveError> {
+        part2(input)
+    }
+}
+
+aoc_test! {
+  input = "
+    1721
+    979
+    366
+    299
+    675
+    1456
+  ";
+
+  part1 = "514579";
+  part2 = "241861950";
+}
@@ -0,0 +1,147 @@
+use aoc_runner_derive::aoc;
+use aoc_runner_derive::aoc_generator;
+use regex::{Match, Regex};
+use std::convert::TryFrom;
+
+use crate::aoc_test;
+use crate::error::{check_lines, parse_lines, ParseError, SolveError};
+use crate::input::normalize;
+use crate::solution::Solution;
+
+#[derive(Debug)]
+pub struct PasswordPolicy {
+    min: u8,
+    max: u8,
+    letter: char,
+    password: String,
+}
+
+#[aoc_generator(day2)]
+fn generator(input: &str) -> Result<Vec<PasswordPolicy>, ParseError> {
+    let input = normalize(input);
+
+    parse_lines(2, &input, parse_line)
+}
+
+fn parse_line(line: &str) -> Result<PasswordPolicy, ParseError> {
+    lazy_static! {
+        static ref RE: Regex =
+            Regex::new(r"(\d+)-(\d+) (\w): (\w+)").expect("Failed to initialize regex");
+    }
+
+    let caps = RE
+        .captures(line)
+        .ok_or_else(|| ParseError::new("Unrecognized password policy", line))?;
+
+    let min = parse_number(caps.get(1), line)?;
+    let max = parse_number(caps.get(2), line)?;
+    let letter = parse_char(caps.get(3), line)?;
+    let password = parse_string(caps.get(4), line)?;
+
+    Ok(PasswordPolicy {
+        min,
+        max,
+        letter,
+        password,
+    })
+}
+
+fn parse_number(input: Option<Match>, line: &str) -> Result<u8, ParseError> {
+    let input = input.ok_or_else(|| ParseError::new("Missing input value", line))?;
+
+    input.as_str().parse::<u8>().map_err(|_| {
+        ParseError::new("Unable to parse number", input.as_str()).with_column(input.start() + 1)
+    })
+}
+
+fn parse_char(input: Option<Match>, line: &str) -> Result<char, ParseError> {
+    let input = input.ok_or_else(|| ParseError::new("Missing input value", line))?;
+
+    input.as_str().chars().next().ok_or_else(|| {
+        ParseError::new("Unable to get char", input.as_str()).with_column(input.start() + 1)
+    })
+}
+
+fn parse_string(input: Option<Match>, line: &str) -> Result<String, ParseError> {
+    let input = input.ok_or_else(|| ParseError::new("Missing input value", line))?;
+
+    Ok(String::from(input.as_str()))
+}
+
+#[aoc(day2, part1)]
+fn part1(policies: &[PasswordPolicy]) -> Result<u16, SolveError> {
+    let valid = policies
+        .iter()
+        .filter(|policy| {
+            let matches = policy.password.matches(policy.letter).count();
+
+            (policy.min as usize..=policy.max as usize).contains(&matches)
+        })
+        .count();
+
+    count(valid)
+}
+
+#[aoc(day2, part2)]
+fn part2(policies: &[PasswordPolicy]) -> Result<u16, SolveError> {
+    let valid = policies
+        .iter()
+        .filter(|policy| {
+            // Positions past either end of the password can't hold the letter
+            let min_result = char_at(&policy.password, policy.min as usize) == Some(policy.letter);
+            let max_result = char_at(&policy.password, policy.max as usize) == Some(policy.letter);
+
+            min_result != max_result
+        })
+        .count();
+
+    count(valid)
+}
+
+/// The character at 1-based position `i`, if there is one
+fn char_at(s: &str, i: usize) -> Option<char> {
+    s.chars().nth(i.checked_sub(1)?)
+}
+
+fn count(valid: usize) -> Result<u16, SolveError> {
+    u16::try_from(valid).map_err(|_| {
+        SolveError::NoAnswer(format!("{} valid passwords is too many to count", valid))
+    })
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<PasswordPolicy>;
+    type Output1 = u16;
+    type Output2 = u16;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        generator(input)
+    }
+
+    fn check(input: &str) -> Vec<ParseError> {
+        check_lines(2, &normalize(input), parse_line)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Output1, SolveError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Output2, SolveError> {
+        part2(input)
+    }
+}
+
+aoc_test! {
+  input = "
+    1-3 a: abcde
+    1-3 b: cdefg
+    2-9 c: ccccccccc
+  ";
+
+  part1 = "2";
+  part2 = "1";
+}
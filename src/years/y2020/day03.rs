@@ -0,0 +1,136 @@
+use aoc_runner_derive::aoc;
+use aoc_runner_derive::aoc_generator;
+use std::fmt;
+
+use crate::aoc_test;
+use crate::error::{ParseError, SolveError};
+use crate::grid::{Grid, Point, Topology};
+use crate::input::normalize;
+use crate::params::Param;
+use crate::solution::Solution;
+
+/// How far right and down each step goes in part 1
+const SLOPE: Param<(usize, usize)> = Param::new(3, "slope", || (3, 1));
+/// Every slope whose trees part 2 multiplies together
+const SLOPES: Param<Vec<(usize, usize)>> =
+    Param::new(3, "slopes", || vec![(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)]);
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Tile {
+    Tree,
+    Empty,
+}
+
+impl fmt::Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Tile::Tree => write!(f, "#"),
+            Tile::Empty => write!(f, "."),
+        }
+    }
+}
+
+/// The slope repeats to the right forever, and wrapping vertically too is harmless since no slope goes past the bottom
+pub type WrapGrid = Grid<Tile>;
+
+#[aoc_generator(day3)]
+fn generator(input: &str) -> Result<WrapGrid, ParseError> {
+    let input = normalize(input);
+
+    Grid::parse(&input, Topology::Wrapping, |tile| match tile {
+        '#' => Ok(Tile::Tree),
+        '.' => Ok(Tile::Empty),
+        _ => Err(ParseError::new("Unrecognized character", tile.to_string())),
+    })
+    .map_err(|e| e.with_day(3))
+}
+
+#[aoc(day3, part1)]
+fn part1(input: &WrapGrid) -> Result<u32, SolveError> {
+    let (step_x, step_y) = descending(&SLOPE, SLOPE.get()?)?;
+
+    Ok(num_trees(input, step_x, step_y))
+}
+
+#[aoc(day3, part2)]
+fn part2(input: &WrapGrid) -> Result<u32, SolveError> {
+    SLOPES
+        .get()?
+        .into_iter()
+        .map(|slope| {
+            descending(&SLOPES, slope).map(|(step_x, step_y)| num_trees(input, step_x, step_y))
+        })
+        .product()
+}
+
+/// A slope that never goes down would never reach the bottom
+fn descending<T>(param: &Param<T>, slope: (usize, usize)) -> Result<(usize, usize), SolveError> {
+    if slope.1 == 0 {
+        return Err(SolveError::InvalidParameter {
+            name: param.name.to_owned(),
+            reason: "Every slope needs to go down".to_owned(),
+        });
+    }
+
+    Ok(slope)
+}
+
+fn num_trees(input: &WrapGrid, step_x: usize, step_y: usize) -> u32 {
+    let mut trees = 0;
+
+    let mut x = step_x;
+    let mut y = step_y;
+
+    while y < input.height() {
+        if let Some(Tile::Tree) = input.get(Point::new(x as i64, y as i64)) {
+            trees += 1;
+        }
+
+        x += step_x;
+        y += step_y;
+    }
+
+    trees
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const PARAMS: &'static [&'static str] = &[SLOPE.name, SLOPES.name];
+
+    type Input = WrapGrid;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Output1, SolveError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Output2, SolveError> {
+        part2(input)
+    }
+}
+
+aoc_test! {
+  input = "
+    ..##.......
+    #...#...#..
+    .#....#..#.
+    ..#.#...#.#
+    .#...##..#.
+    ..#.##.....
+    .#.#.#....#
+    .#........#
+    #.##...#...
+    #...##....#
+    .#..#...#.#
+  ";
+
+  part1 = "7";
+  part2 = "336";
+}
@@ -0,0 +1,273 @@
+use aoc_runner_derive::aoc;
+use aoc_runner_derive::aoc_generator;
+use regex::Regex;
+use std::fmt::Debug;
+
+use crate::aoc_test;
+use crate::error::{ParseError, SolveError};
+use crate::input::{blocks, normalize};
+use crate::solution::Solution;
+
+#[aoc_generator(day4)]
+fn generator(input: &str) -> Result<Vec<Passport>, ParseError> {
+    let mut errors = Vec::new();
+    let passports = parse_passports(&normalize(input), &mut errors);
+
+    match errors.into_iter().next() {
+        Some(e) => Err(e),
+        None => Ok(passports),
+    }
+}
+
+/// Parses every passport, collecting bad fields into `errors` instead of stopping at the first one
+fn parse_passports(input: &str, errors: &mut Vec<ParseError>) -> Vec<Passport> {
+    // Passport records are separated by blank lines
+    let mut passports = Vec::new();
+    for block in blocks(input) {
+        let mut passport = Passport::new();
+
+        for (line, text) in block.numbered_lines() {
+            // Fields are separated by single spaces, so keep track of where each one starts to point at bad fields
+            let mut column = 1;
+            for field in text.split(' ') {
+                if !field.is_empty() {
+                    if let Err(e) = passport.set_field(field) {
+                        errors.push(e.with_day(4).with_line(line).with_column(column));
+                    }
+                }
+
+                column += field.len() + 1;
+            }
+        }
+
+        passports.push(passport);
+    }
+
+    passports
+}
+
+#[aoc(day4, part1)]
+fn part1(input: &[Passport]) -> u16 {
+    input
+        .iter()
+        .filter(|passport| passport.has_required_fields())
+        .count() as u16
+}
+
+const VALID_ECLS: [&str; 7] = ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"];
+
+#[derive(Debug)]
+pub struct Passport {
+    byr: Option<String>,
+    iyr: Option<String>,
+    eyr: Option<String>,
+    hgt: Option<String>,
+    hcl: Option<String>,
+    ecl: Option<String>,
+    pid: Option<String>,
+    cid: Option<String>,
+}
+
+impl Passport {
+    fn new() -> Passport {
+        Passport {
+            byr: Option::None,
+            iyr: Option::None,
+            eyr: Option::None,
+            hgt: Option::None,
+            hcl: Option::None,
+            ecl: Option::None,
+            pid: Option::None,
+            cid: Option::None,
+        }
+    }
+
+    fn set_field(&mut self, field: &str) -> Result<(), ParseError> {
+        let mut parts = field.splitn(2, ':');
+        let key = parts.next().unwrap_or_default();
+        let value = parts
+            .next()
+            .ok_or_else(|| ParseError::new("Missing ':' in field", field))?
+            .to_owned();
+
+        match key {
+            "byr" => self.byr = Some(value),
+            "iyr" => self.iyr = Some(value),
+            "eyr" => self.eyr = Some(value),
+            "hgt" => self.hgt = Some(value),
+            "hcl" => self.hcl = Some(value),
+            "ecl" => self.ecl = Some(value),
+            "pid" => self.pid = Some(value),
+            "cid" => self.cid = Some(value),
+            _ => return Err(ParseError::new("Unknown key", key)),
+        }
+
+        Ok(())
+    }
+
+    // The cid field doesn't matter, every other field has to be present
+    fn has_required_fields(&self) -> bool {
+        self.byr.is_some()
+            && self.iyr.is_some()
+            && self.eyr.is_some()
+            && self.hgt.is_some()
+            && self.hcl.is_some()
+            && self.ecl.is_some()
+            && self.pid.is_some()
+    }
+
+    fn is_valid(&self) -> bool {
+        self.valid_byr()
+            && self.valid_iyr()
+            && self.valid_eyr()
+            && self.valid_hgt()
+            && self.valid_hcl()
+            && self.valid_ecl()
+            && self.valid_pid()
+            && self.valid_cid()
+    }
+
+    fn valid_byr(&self) -> bool {
+        match &self.byr {
+            Some(byr) => {
+                let byr = byr.parse::<u16>();
+
+                match byr {
+                    Ok(byr) => (1920..=2002).contains(&byr),
+                    Err(_) => false,
+                }
+            }
+            None => false,
+        }
+    }
+
+    fn valid_iyr(&self) -> bool {
+        match &self.iyr {
+            Some(iyr) => {
+                let iyr = iyr.parse::<u16>();
+
+                match iyr {
+                    Ok(iyr) => (2010..=2020).contains(&iyr),
+                    Err(_) => false,
+                }
+            }
+            None => false,
+        }
+    }
+
+    fn valid_eyr(&self) -> bool {
+        match &self.eyr {
+            Some(eyr) => {
+                let eyr = eyr.parse::<u16>();
+
+                match eyr {
+                    Ok(eyr) => (2020..=2030).contains(&eyr),
+                    Err(_) => false,
+                }
+            }
+            None => false,
+        }
+    }
+
+    fn valid_hgt(&self) -> bool {
+        match &self.hgt {
+            Some(hgt) if hgt.len() > 2 => {
+                let value = hgt[..hgt.len() - 2].parse::<u16>();
+
+                match value {
+                    Ok(value) => match &hgt[hgt.len() - 2..] {
+                        "cm" => (150..=193).contains(&value),
+                        "in" => (59..=76).contains(&value),
+                        _ => false,
+                    },
+                    Err(_) => false,
+                }
+            }
+            _ => false,
+        }
+    }
+
+    fn valid_hcl(&self) -> bool {
+        lazy_static! {
+            static ref RE: Regex = Regex::new(r"#[0-9a-f]{6}").expect("Failed to initialize regex");
+        }
+
+        match &self.hcl {
+            Some(hcl) => RE.is_match(hcl.as_str()),
+            None => false,
+        }
+    }
+
+    fn valid_ecl(&self) -> bool {
+        match &self.ecl {
+            Some(ecl) => VALID_ECLS.contains(&ecl.as_str()),
+            None => false,
+        }
+    }
+
+    fn valid_pid(&self) -> bool {
+        match &self.pid {
+            Some(pid) => pid.len() == 9 && pid.parse::<u32>().is_ok(),
+            None => false,
+        }
+    }
+
+    fn valid_cid(&self) -> bool {
+        true
+    }
+}
+
+#[aoc(day4, part2)]
+fn part2(input: &[Passport]) -> u16 {
+    input.iter().filter(|passport| passport.is_valid()).count() as u16
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Vec<Passport>;
+    type Output1 = u16;
+    type Output2 = u16;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        generator(input)
+    }
+
+    fn check(input: &str) -> Vec<ParseError> {
+        let mut errors = Vec::new();
+        parse_passports(&normalize(input), &mut errors);
+
+        errors
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Output1, SolveError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Output2, SolveError> {
+        Ok(part2(input))
+    }
+}
+
+aoc_test! {
+  input = "
+    ecl:gry pid:860033327 eyr:2020 hcl:#fffffd
+    byr:1937 iyr:2017 cid:147 hgt:183cm
+
+    iyr:2013 ecl:amb cid:350 eyr:2023 pid:028048884
+    hcl:#cfa07d byr:1929
+
+    hcl:#ae17e1 iyr:2013
+    eyr:2024
+    ecl:brn pid:760753108 byr:1931
+    hgt:179cm
+
+    hcl:#cfa07d eyr:2025 pid:166559648
+    iyr:2011 ecl:brn hgt:59in
+  ";
+
+  part1 = "2";
+  part2 = "2";
+}
@@ -0,0 +1,176 @@
+use aoc_runner_derive::aoc;
+use aoc_runner_derive::aoc_generator;
+use std::cmp::Ordering;
+
+use crate::aoc_test;
+use crate::error::{check_lines, parse_lines, ParseError, SolveError};
+use crate::input::normalize;
+use crate::solution::Solution;
+
+#[aoc_generator(day5)]
+fn generator(input: &str) -> Result<Vec<Vec<Direction>>, ParseError> {
+    let input = normalize(input);
+
+    parse_lines(5, &input, parse_line)
+}
+
+fn parse_line(line: &str) -> Result<Vec<Direction>, ParseError> {
+    line.chars()
+        .enumerate()
+        .map(|(i, c)| match c {
+            'F' => Ok(Direction::Front),
+            'B' => Ok(Direction::Back),
+            'L' => Ok(Direction::Left),
+            'R' => Ok(Direction::Right),
+            _ => Err(ParseError::new("Unrecognized character", c.to_string()).with_column(i + 1)),
+        })
+        .collect()
+}
+
+const MAX_Y: u8 = 127;
+const MAX_X: u8 = 7;
+
+pub enum Direction {
+    Front,
+    Back,
+    Left,
+    Right,
+}
+
+#[derive(Eq)]
+struct Seat {
+    row: u8,
+    col: u8,
+}
+
+impl Seat {
+    fn new(partitions: &[Direction]) -> Seat {
+        let mut front = 0;
+        let mut back = MAX_Y;
+        let mut left = 0;
+        let mut right = MAX_X;
+
+        for partition in partitions {
+            match partition {
+                Direction::Front => back = (front + back) / 2,
+                Direction::Back => front = ((front + back) / 2) + 1,
+                Direction::Left => right = (left + right) / 2,
+                Direction::Right => left = ((left + right) / 2) + 1,
+            }
+        }
+
+        Seat {
+            row: front,
+            col: left,
+        }
+    }
+
+    fn seat_id(&self) -> u16 {
+        (self.row as u16 * 8) + self.col as u16
+    }
+}
+
+impl Ord for Seat {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.seat_id().cmp(&other.seat_id())
+    }
+}
+
+impl PartialOrd for Seat {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Seat {
+    fn eq(&self, other: &Self) -> bool {
+        self.seat_id() == other.seat_id()
+    }
+}
+
+#[aoc(day5, part1)]
+fn part1(input: &[Vec<Direction>]) -> Result<u16, SolveError> {
+    let mut seats = Vec::new();
+
+    for seat in input {
+        seats.push(Seat::new(seat));
+    }
+
+    seats
+        .iter()
+        .max()
+        .map(Seat::seat_id)
+        .ok_or_else(|| SolveError::NoAnswer("There are no boarding passes".to_owned()))
+}
+
+#[aoc(day5, part2)]
+fn part2(input: &[Vec<Direction>]) -> Result<u16, SolveError> {
+    let mut seats = Vec::new();
+
+    for seat in input {
+        seats.push(Seat::new(seat).seat_id());
+    }
+
+    seats.sort();
+
+    seats
+        .iter()
+        .enumerate()
+        .find(|(i, &id)| match seats.get(i + 1) {
+            Some(&next_id) => next_id != id + 1,
+            None => false,
+        })
+        .map(|(_, &id)| id + 1)
+        .ok_or_else(|| SolveError::NoAnswer("There's no gap between seat IDs".to_owned()))
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = Vec<Vec<Direction>>;
+    type Output1 = u16;
+    type Output2 = u16;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        generator(input)
+    }
+
+    fn check(input: &str) -> Vec<ParseError> {
+        check_lines(5, &normalize(input), parse_line)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Output1, SolveError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Output2, SolveError> {
+        part2(input)
+    }
+}
+
+aoc_test! {
+  example boarding_passes {
+    input = "
+      FBFBBFFRLR
+      BFFFBBFRRR
+      FFFBBBFRRR
+      BBFFBBFRLL
+    ";
+
+    part1 = "820";
+  }
+
+  // Seats 357 and 358 from the first pass on, then 360, leaving 359 as the only gap
+  example missing_seat {
+    input = "
+      FBFBBFFRLR
+      FBFBBFFRRL
+      FBFBBFBLLL
+    ";
+
+    part1 = "360";
+    part2 = "359";
+  }
+}
@@ -0,0 +1,119 @@
+use aoc_runner_derive::aoc;
+use aoc_runner_derive::aoc_generator;
+
+use itertools::Itertools;
+
+use crate::aoc_test;
+use crate::error::{ParseError, SolveError};
+use crate::input::{blocks, normalize};
+use crate::solution::Solution;
+
+#[aoc_generator(day6)]
+fn generator(input: &str) -> Result<Vec<String>, ParseError> {
+    let input = normalize(input);
+
+    // Groups are separated by blank lines
+    let mut groups = Vec::new();
+    for block in blocks(&input) {
+        // Answers are only ever questions a-z
+        for (line, text) in block.numbered_lines() {
+            if let Some((column, c)) = text.char_indices().find(|(_, c)| !c.is_ascii_lowercase()) {
+                return Err(ParseError::new("Unrecognized question", c.to_string())
+                    .with_day(6)
+                    .with_line(line)
+                    .with_column(column + 1));
+            }
+        }
+
+        groups.push(block.lines.join("\n"));
+    }
+
+    Ok(groups)
+}
+
+#[aoc(day6, part1)]
+fn part1(input: &[String]) -> u32 {
+    input
+        .iter()
+        .map(|group| group.lines().join("").chars().unique().count() as u32)
+        .sum::<u32>()
+}
+
+#[aoc(day6, part2)]
+fn part2(input: &[String]) -> Result<u32, SolveError> {
+    input
+        .iter()
+        .enumerate()
+        .map(|(i, group)| {
+            let group: Vec<&str> = group.lines().collect();
+            let members = group.len();
+
+            let first_person = group
+                .first()
+                .ok_or_else(|| SolveError::NoAnswer(format!("Group {} is empty", i + 1)))?;
+            let group_string = group.join("");
+
+            let res = first_person
+                .chars()
+                .map(|c| group_string.matches(c).count())
+                .filter(|&count| count == members)
+                .count();
+
+            Ok(res as u32)
+        })
+        .sum()
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = Vec<String>;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Output1, SolveError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Output2, SolveError> {
+        part2(input)
+    }
+}
+
+aoc_test! {
+  example groups {
+    input = "
+      abc
+
+      a
+      b
+      c
+
+      ab
+      ac
+
+      a
+      a
+      a
+      a
+
+      b
+    ";
+
+    part1 = "11";
+    part2 = "6";
+  }
+
+  example crlf {
+    file = "crlf.txt";
+
+    part1 = "11";
+    part2 = "6";
+  }
+}
@@ -0,0 +1,178 @@
+use aoc_runner_derive::aoc;
+use aoc_runner_derive::aoc_generator;
+use pest::error::LineColLocation;
+use pest::Parser;
+use petgraph::graph::NodeIndex;
+use std::collections::HashMap;
+
+use crate::aoc_test;
+use crate::error::{ParseError, SolveError};
+use crate::graph::LabeledGraph;
+use crate::input::normalize;
+use crate::params::Param;
+use crate::solution::Solution;
+use crate::trace;
+
+/// The bag both parts are asked about
+const BAG: Param<String> = Param::new(7, "bag", || "shiny gold".to_owned());
+
+#[derive(Parser)]
+#[grammar = "../resources/2020/day07.pest"]
+struct BagParser;
+
+#[derive(Debug)]
+pub struct BagContents {
+    color: String,
+    count: u8,
+}
+
+/// Which bags hold which, and how many of them
+pub type Bags = LabeledGraph<String, u8>;
+
+#[aoc_generator(day7)]
+fn generator(input: &str) -> Result<Bags, ParseError> {
+    let input = normalize(input);
+
+    let mut bags: HashMap<String, Vec<BagContents>> = HashMap::new();
+
+    for (i, line) in input.lines().enumerate() {
+        let (bag, contents) = parse_line(line).map_err(|e| e.with_day(7).with_line(i + 1))?;
+
+        bags.entry(bag).or_default().extend(contents);
+    }
+
+    let mut graph = LabeledGraph::new();
+
+    for (bag, contents) in bags {
+        // Bags that are only ever mentioned as contents don't have a rule of their own, so they hold nothing
+        graph.add_node(bag.clone());
+
+        for inner in contents {
+            graph.add_edge(bag.clone(), inner.color, inner.count);
+        }
+    }
+
+    Ok(graph)
+}
+
+/// One rule: the color of a bag and what it has to hold
+pub fn parse_line(line: &str) -> Result<(String, Vec<BagContents>), ParseError> {
+    // Its overkill using a parser generator for this but I'll be damned if I never use the stuff I learned in Compilers
+    let rule = BagParser::parse(Rule::input, line)
+        .map_err(|e| {
+            let column = match e.line_col {
+                LineColLocation::Pos((_, column)) => column,
+                LineColLocation::Span((_, column), _) => column,
+            };
+
+            ParseError::new(e.variant.message(), line.get(column - 1..).unwrap_or(line))
+                .with_column(column)
+        })?
+        .next()
+        .unwrap()
+        .into_inner()
+        .next()
+        .unwrap();
+
+    let mut inner_rules = rule.into_inner();
+    let bag = inner_rules.next().unwrap().as_str().to_owned();
+
+    let mut contents = Vec::new();
+    for inner_bag in inner_rules.next().unwrap().into_inner() {
+        let mut inner_bag = inner_bag.into_inner();
+
+        let count = inner_bag.next().unwrap();
+        let column = count.as_span().start() + 1;
+        let count = count.as_str().parse::<u8>().map_err(|_| {
+            ParseError::new("Unable to parse string into u8", count.as_str()).with_column(column)
+        })?;
+        let color = inner_bag.next().unwrap().as_str().to_owned();
+
+        contents.push(BagContents { color, count });
+    }
+
+    Ok((bag, contents))
+}
+
+fn find_bag(graph: &Bags) -> Result<NodeIndex, SolveError> {
+    let bag = BAG.get()?;
+
+    graph
+        .index(&bag)
+        .ok_or_else(|| SolveError::NoAnswer(format!("There are no {} bags", bag)))
+}
+
+#[aoc(day7, part1)]
+fn part1(input: &Bags) -> Result<usize, SolveError> {
+    let target = find_bag(input)?;
+
+    let holders = input.ancestors(target);
+    for &holder in holders.iter() {
+        trace!(
+            7,
+            "{} can hold {}",
+            input.label(holder),
+            input.label(target)
+        );
+    }
+
+    Ok(holders.len())
+}
+
+#[aoc(day7, part2)]
+fn part2(input: &Bags) -> Result<u64, SolveError> {
+    let target = find_bag(input)?;
+
+    if trace::enabled(7) {
+        for &inner in input.descendants(target).iter() {
+            trace!(
+                7,
+                "{} is {} bags including itself",
+                input.label(inner),
+                input.path_products(inner)? + 1
+            );
+        }
+    }
+
+    Ok(input.path_products(target)?)
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const PARAMS: &'static [&'static str] = &[BAG.name];
+
+    type Input = Bags;
+    type Output1 = usize;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Output1, SolveError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Output2, SolveError> {
+        part2(input)
+    }
+}
+
+aoc_test! {
+  input = "
+    light red bags contain 1 bright white bag, 2 muted yellow bags.
+    dark orange bags contain 3 bright white bags, 4 muted yellow bags.
+    bright white bags contain 1 shiny gold bag.
+    muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
+    shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.
+    dark olive bags contain 3 faded blue bags, 4 dotted black bags.
+    vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.
+    faded blue bags contain no other bags.
+    dotted black bags contain no other bags.
+  ";
+
+  part1 = "4";
+  part2 = "32";
+}
@@ -0,0 +1,243 @@
+use aoc_runner_derive::aoc;
+use aoc_runner_derive::aoc_generator;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::aoc_test;
+use crate::error::{check_lines, parse_lines, ParseError, SolveError};
+use crate::input::normalize;
+use crate::solution::Solution;
+use crate::trace;
+use crate::vm::{self, Control, Fault, Flow, LoopCheck, Machine, Outcome, State, Word};
+
+#[derive(Debug, Clone, Copy)]
+pub enum Operation {
+    Acc,
+    Jmp,
+    Nop,
+}
+
+impl FromStr for Operation {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "acc" => Ok(Operation::Acc),
+            "jmp" => Ok(Operation::Jmp),
+            "nop" => Ok(Operation::Nop),
+            _ => Err(ParseError::new("Invalid instruction", s)),
+        }
+    }
+}
+
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Operation::Acc => write!(f, "acc"),
+            Operation::Jmp => write!(f, "jmp"),
+            Operation::Nop => write!(f, "nop"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Instruction {
+    op: Operation,
+    arg: i32,
+}
+
+/// The handheld's only register
+const ACCUMULATOR: usize = 0;
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = s.split_whitespace().collect();
+
+        if parts.len() != 2 {
+            return Err(ParseError::new("Invalid number of arguments", s));
+        }
+
+        // Either part can have any amount of whitespace before it, so look for where each one actually starts
+        let op_start = s.find(parts[0]).unwrap_or(0);
+        let arg_start = op_start + parts[0].len();
+        let arg_start = arg_start + s[arg_start..].find(parts[1]).unwrap_or(0);
+
+        let op = Operation::from_str(parts[0]).map_err(|e| e.with_column(op_start + 1))?;
+        let arg = parts[1].parse::<i32>().map_err(|_| {
+            ParseError::new("Arg is not an i32", parts[1]).with_column(arg_start + 1)
+        })?;
+
+        Ok(Instruction { op, arg })
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {:+}", self.op, self.arg)
+    }
+}
+
+impl vm::Instruction for Instruction {
+    const REGISTERS: usize = 1;
+
+    fn execute(&self, state: &mut State) -> Result<Flow, Fault> {
+        match self.op {
+            Operation::Acc => {
+                let acc = state.register(ACCUMULATOR)?;
+                let acc = acc.checked_add(self.arg.into()).ok_or(Fault::Overflow)?;
+
+                state.set_register(ACCUMULATOR, acc)?;
+                Ok(Flow::Next)
+            }
+            Operation::Jmp => Ok(Flow::Jump(self.arg.into())),
+            Operation::Nop => Ok(Flow::Next),
+        }
+    }
+}
+
+/// Every program runs each instruction at most once before it loops, as no jump depends on the accumulator
+pub type Cpu = Machine<Instruction>;
+
+fn accumulator(cpu: &Cpu) -> Word {
+    cpu.state.registers()[ACCUMULATOR]
+}
+
+#[aoc_generator(day8)]
+fn generator(input: &str) -> Result<Cpu, ParseError> {
+    let input = normalize(input);
+
+    let instructions = parse_lines(8, &input, Instruction::from_str)?;
+
+    Ok(Machine::new(instructions).with_loop_check(LoopCheck::Pc))
+}
+
+#[aoc(day8, part1)]
+fn part1(input: &Cpu) -> Result<Word, SolveError> {
+    let mut input = (*input).clone();
+
+    let outcome = input.run_with(&mut |state: &State, instruction: &Instruction| {
+        trace!(
+            8,
+            "pc {}: {}, accumulator {}",
+            state.pc,
+            instruction,
+            state.registers()[ACCUMULATOR]
+        );
+        Control::Continue
+    })?;
+
+    match outcome {
+        Outcome::Looped { pc } => {
+            trace!(
+                8,
+                "pc {} runs a second time with accumulator {}",
+                pc,
+                accumulator(&input)
+            );
+
+            Ok(accumulator(&input))
+        }
+        // A program that runs off the end never repeats an instruction, so there's nothing to report
+        Outcome::Halted => Err(SolveError::NoAnswer(
+            "The program terminated without repeating an instruction".to_owned(),
+        )),
+        Outcome::Faulted { pc, fault } => Err(SolveError::NoAnswer(format!(
+            "The program faulted at pc {}: {}",
+            pc, fault
+        ))),
+        Outcome::Stopped { .. } => unreachable!("The hook never stops the program"),
+    }
+}
+
+#[aoc(day8, part2)]
+fn part2(input: &Cpu) -> Result<Word, SolveError> {
+    for (i, instruction) in input.program.iter().enumerate() {
+        // Only jmp and nop instructions can be swapped
+        let op = match instruction.op {
+            Operation::Jmp => Operation::Nop,
+            Operation::Nop => Operation::Jmp,
+            Operation::Acc => continue,
+        };
+
+        let mut cpu = input.clone();
+        cpu.program[i] = Instruction {
+            op,
+            arg: instruction.arg,
+        };
+
+        match cpu.run()? {
+            Outcome::Halted => {
+                trace!(
+                    8,
+                    "swapping pc {} to {} terminates with accumulator {}",
+                    i,
+                    cpu.program[i],
+                    accumulator(&cpu)
+                );
+
+                return Ok(accumulator(&cpu));
+            }
+            outcome => {
+                trace!(
+                    8,
+                    "swapping pc {} to {} {}",
+                    i,
+                    cpu.program[i],
+                    match outcome {
+                        Outcome::Faulted { .. } => "jumps outside the program",
+                        _ => "loops",
+                    }
+                );
+            }
+        }
+    }
+
+    Err(SolveError::NoAnswer(
+        "No single jmp/nop swap makes the program terminate".to_owned(),
+    ))
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = Cpu;
+    type Output1 = Word;
+    type Output2 = Word;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        generator(input)
+    }
+
+    fn check(input: &str) -> Vec<ParseError> {
+        check_lines(8, &normalize(input), Instruction::from_str)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Output1, SolveError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Output2, SolveError> {
+        part2(input)
+    }
+}
+
+aoc_test! {
+  input = "
+    nop +0
+    acc +1
+    jmp +4
+    acc +3
+    jmp -3
+    acc -99
+    acc +1
+    jmp -4
+    acc +6
+  ";
+
+  part1 = "5";
+  part2 = "8";
+}
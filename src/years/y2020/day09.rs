@@ -0,0 +1,187 @@
+use aoc_runner_derive::aoc;
+use aoc_runner_derive::aoc_generator;
+use itertools::Itertools;
+use std::collections::VecDeque;
+use std::iter::FromIterator;
+
+use crate::aoc_test;
+use crate::budget::Budget;
+use crate::error::{check_lines, parse_lines, ParseError, SolveError};
+use crate::input::normalize;
+use crate::params::Param;
+use crate::solution::Solution;
+
+#[aoc_generator(day9)]
+fn generator(input: &str) -> Result<Vec<u64>, ParseError> {
+    let input = normalize(input);
+
+    parse_lines(9, &input, parse_line)
+}
+
+fn parse_line(line: &str) -> Result<u64, ParseError> {
+    line.parse::<u64>()
+        .map_err(|_| ParseError::new("Unable to parse int", line))
+}
+
+/// How many numbers each number can be the sum of two of
+const PREAMBLE: Param<usize> = Param::new(9, "preamble", || 25);
+
+#[aoc(day9, part1)]
+fn part1(input: &[u64]) -> Result<u64, SolveError> {
+    let preamble = PREAMBLE.get()?;
+
+    let input = input.iter();
+    let mut queue = VecDeque::from_iter(input.clone().take(preamble).copied());
+    let input = input.skip(preamble);
+
+    for num in input {
+        let mut found = false;
+
+        // A pair too big to add up can't sum to anything in the input
+        for combination in queue
+            .iter()
+            .tuple_combinations()
+            .map(|(a, b)| a.checked_add(*b))
+        {
+            if Some(*num) == combination {
+                found = true;
+                break;
+            }
+        }
+
+        if !found {
+            return Ok(*num);
+        }
+
+        queue.pop_front();
+        queue.push_back(*num);
+    }
+
+    Err(SolveError::NoAnswer(
+        "Every number is the sum of two before it".to_owned(),
+    ))
+}
+
+#[aoc(day9, part2)]
+fn part2(input: &[u64]) -> Result<u64, SolveError> {
+    let invalid = part1(input)?;
+    let len = input.len();
+
+    // One step per window tried, and there are fewer than len² windows of every length put together
+    let mut budget = Budget::new(len.saturating_mul(len) as u64);
+
+    for i in 2..len {
+        for window in input.windows(i) {
+            budget.tick()?;
+            let sum = window.iter().try_fold(0u64, |sum, n| sum.checked_add(*n));
+
+            if sum == Some(invalid) {
+                let mut window: Vec<u64> = window.to_vec();
+                window.sort();
+
+                let (first, last) = (window[0], window[window.len() - 1]);
+                return first.checked_add(last).ok_or_else(|| {
+                    SolveError::NoAnswer(format!("{} + {} is too big for a u64", first, last))
+                });
+            }
+        }
+    }
+
+    Err(SolveError::NoAnswer(
+        "No run of numbers sums to the invalid number".to_owned(),
+    ))
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    const PARAMS: &'static [&'static str] = &[PREAMBLE.name];
+
+    type Input = Vec<u64>;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        generator(input)
+    }
+
+    fn check(input: &str) -> Vec<ParseError> {
+        check_lines(9, &normalize(input), parse_line)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Output1, SolveError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Output2, SolveError> {
+        part2(input)
+    }
+}
+
+aoc_test! {
+  example full_preamble {
+    input = "
+      1
+      2
+      3
+      4
+      5
+      6
+      7
+      8
+      9
+      10
+      11
+      12
+      13
+      14
+      15
+      16
+      17
+      18
+      19
+      20
+      21
+      22
+      23
+      24
+      25
+      26
+      49
+      100
+      124
+    ";
+
+    part1 = "100";
+    part2 = "74";
+  }
+
+  example short_preamble(preamble = 5) {
+    input = "
+      35
+      20
+      15
+      25
+      47
+      40
+      62
+      55
+      65
+      95
+      102
+      117
+      150
+      182
+      127
+      219
+      299
+      277
+      309
+      576
+    ";
+
+    part1 = "127";
+    part2 = "62";
+  }
+}
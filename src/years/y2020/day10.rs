@@ -0,0 +1,249 @@
+use aoc_runner_derive::aoc;
+use aoc_runner_derive::aoc_generator;
+use std::collections::HashMap;
+
+use crate::aoc_test;
+use crate::error::{check_lines, parse_lines, ParseError, SolveError};
+use crate::input::normalize;
+use crate::solution::Solution;
+
+#[aoc_generator(day10)]
+fn generator(input: &str) -> Result<Vec<u16>, ParseError> {
+    let input = normalize(input);
+
+    parse_lines(10, &input, parse_line)
+}
+
+fn parse_line(line: &str) -> Result<u16, ParseError> {
+    line.parse::<u16>()
+        .map_err(|_| ParseError::new("Unable to parse input", line))
+}
+
+#[aoc(day10, part1)]
+fn part1(input: &[u16]) -> Result<u16, SolveError> {
+    let mut input = Vec::from(input);
+    input.sort();
+
+    let mut one_diffs = 0;
+    let mut three_diffs = 1; // Start at 1, because final adapter -> device will always have a difference of 3
+
+    let mut prev = 0;
+    for &n in &input {
+        match n - prev {
+            1 => one_diffs += 1,
+            2 => (),
+            3 => three_diffs += 1,
+            // Every adapter has to be used, so the chain breaks on duplicates and on gaps too wide to bridge
+            _ => {
+                return Err(SolveError::NoAnswer(format!(
+                    "Adapter {} can't follow {} in the chain",
+                    n, prev
+                )))
+            }
+        }
+
+        prev = n;
+    }
+
+    Ok(one_diffs * three_diffs)
+}
+
+// Made into a struct to make memoization easier
+struct Part2Solver {
+    max: u16,
+    values: Vec<u16>,
+    cache: HashMap<usize, u128>,
+}
+
+impl Part2Solver {
+    fn new(values: &[u16]) -> Result<Part2Solver, SolveError> {
+        // "Clean up" the input vec to make things easier
+        let mut values = Vec::from(values);
+        values.push(0); // Add 0 for the "charging port"
+        values.sort();
+
+        // A gap too wide to bridge means there are no arrangements at all
+        if let Some(pair) = values.windows(2).find(|pair| pair[1] - pair[0] > 3) {
+            return Err(SolveError::NoAnswer(format!(
+                "Adapter {} can't follow {} in the chain",
+                pair[1], pair[0]
+            )));
+        }
+
+        let last = values[values.len() - 1];
+        let max = last.checked_add(3).ok_or_else(|| {
+            SolveError::NoAnswer(format!(
+                "Adapter {} is too big to be followed by the device",
+                last
+            ))
+        })?;
+
+        Ok(Part2Solver {
+            max,
+            values,
+            cache: HashMap::new(),
+        })
+    }
+
+    fn solve(&mut self) -> u128 {
+        self.solve_inner(0)
+    }
+
+    fn solve_inner(&mut self, i: usize) -> u128 {
+        if let Some(answers) = self.cache.get(&i) {
+            return *answers;
+        }
+
+        let val = self.values[i];
+
+        // Base case: the current value + 3 is the max value
+        if self.max - val == 3 {
+            return 1;
+        }
+
+        // Recursive case: for all "next" values between i + 1 to i + 3 (excluding any that are out of bounds)
+        let mut answers = 0;
+
+        let cloned = self.values.clone();
+        let iter = cloned.iter().enumerate();
+        let nexts = iter.skip(i + 1).take(3);
+
+        for (next_i, next_val) in nexts {
+            // If the next value is within a range of 3 of the current value
+            if next_val - val <= 3 {
+                // Find the number of answers from there
+                answers += self.solve_inner(next_i);
+            }
+        }
+
+        self.cache.insert(i, answers);
+
+        answers
+    }
+}
+
+#[aoc(day10, part2)]
+fn part2(input: &[u16]) -> Result<u128, SolveError> {
+    Ok(Part2Solver::new(input)?.solve())
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Vec<u16>;
+    type Output1 = u16;
+    type Output2 = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        generator(input)
+    }
+
+    fn check(input: &str) -> Vec<ParseError> {
+        check_lines(10, &normalize(input), parse_line)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Output1, SolveError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Output2, SolveError> {
+        part2(input)
+    }
+}
+
+aoc_test! {
+  example small {
+    input = "
+      16
+      10
+      15
+      5
+      1
+      11
+      7
+      19
+      6
+      12
+      4
+    ";
+
+    part1 = "35";
+    part2 = "8";
+  }
+
+  example larger {
+    file = "larger.txt";
+
+    part1 = "220";
+    part2 = "19208";
+  }
+}
+
+// Cross-checks the memoized solver against counting every subset of adapters by brute force
+#[cfg(test)]
+mod properties {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn is_chain(adapters: &[u16]) -> bool {
+        let mut prev = 0;
+
+        for &adapter in adapters {
+            if adapter - prev > 3 {
+                return false;
+            }
+
+            prev = adapter;
+        }
+
+        true
+    }
+
+    fn reference_part2(adapters: &[u16]) -> u128 {
+        let mut sorted = Vec::from(adapters);
+        sorted.sort_unstable();
+        let last = *sorted.last().expect("At least one adapter");
+
+        // The last adapter has to be used to reach the device, so only the rest can be left out
+        let rest = &sorted[..sorted.len() - 1];
+        (0..1u32 << rest.len())
+            .filter(|subset| {
+                let mut chain: Vec<u16> = rest
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| subset & (1 << i) != 0)
+                    .map(|(_, &adapter)| adapter)
+                    .collect();
+                chain.push(last);
+
+                is_chain(&chain)
+            })
+            .count() as u128
+    }
+
+    /// Adapter sets where every adapter is 1-3 jolts above the previous one, so the full chain always works
+    fn adapters() -> impl Strategy<Value = Vec<u16>> {
+        prop::collection::vec(1u16..=3, 1..14)
+            .prop_map(|gaps| {
+                gaps.iter()
+                    .scan(0, |joltage, gap| {
+                        *joltage += gap;
+                        Some(*joltage)
+                    })
+                    .collect::<Vec<u16>>()
+            })
+            .prop_shuffle()
+    }
+
+    proptest! {
+        #[test]
+        fn part2_matches_reference(adapters in adapters()) {
+            let input = adapters.iter().map(u16::to_string).collect::<Vec<String>>().join("\n");
+            let parsed = generator(&input).unwrap();
+
+            prop_assert_eq!(part2(&parsed).unwrap(), reference_part2(&adapters));
+        }
+    }
+}
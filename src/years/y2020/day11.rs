@@ -0,0 +1,145 @@
+use aoc_runner_derive::aoc;
+use aoc_runner_derive::aoc_generator;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::aoc_test;
+use crate::budget::Budget;
+use crate::error::{ParseError, SolveError};
+use crate::grid::{Grid, Point, Topology};
+use crate::input::normalize;
+use crate::params::Param;
+use crate::solution::Solution;
+use crate::trace;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Seat {
+    Occupied,
+    Empty,
+    Floor,
+}
+
+impl FromStr for Seat {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "L" => Ok(Self::Empty),
+            "#" => Ok(Self::Occupied),
+            "." => Ok(Self::Floor),
+            _ => Err(ParseError::new("Invalid input string", s)),
+        }
+    }
+}
+
+impl fmt::Display for Seat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Seat::Occupied => write!(f, "#"),
+            Seat::Empty => write!(f, "L"),
+            Seat::Floor => write!(f, "."),
+        }
+    }
+}
+
+/// Real inputs settle within a hundred or so generations, so a grid still changing after this many never will
+const MAX_GENERATIONS: u64 = 10_000;
+
+/// How many occupied neighbours make someone leave their seat in part 1
+const CROWDED1: Param<usize> = Param::new(11, "part1_crowded", || 4);
+/// How many occupied seats in view make someone leave their seat in part 2
+const CROWDED2: Param<usize> = Param::new(11, "part2_crowded", || 5);
+
+/// Runs the seating rules until nobody moves, where `occupied` counts the occupied seats someone at a point pays
+/// attention to, and returns how many seats end up occupied.
+fn settle<F>(grid: &Grid<Seat>, crowded: usize, occupied: F) -> Result<usize, SolveError>
+where
+    F: Fn(&Grid<Seat>, Point) -> usize,
+{
+    let mut grid = grid.clone();
+    let mut generations = Budget::new(MAX_GENERATIONS);
+
+    loop {
+        let next = grid.map(|point, &seat| match seat {
+            Seat::Occupied if occupied(&grid, point) >= crowded => Seat::Empty,
+            Seat::Empty if occupied(&grid, point) == 0 => Seat::Occupied,
+            _ => seat,
+        });
+
+        if next == grid {
+            break;
+        }
+
+        grid = next;
+        generations.tick()?;
+        trace!(
+            11,
+            "generation {}: {} occupied",
+            generations.steps(),
+            grid.count(|&seat| seat == Seat::Occupied)
+        );
+    }
+    trace!(11, "settled after {} generations", generations.steps());
+
+    Ok(grid.count(|&seat| seat == Seat::Occupied))
+}
+
+#[aoc_generator(day11)]
+fn generator(input: &str) -> Result<Grid<Seat>, ParseError> {
+    let input = normalize(input);
+
+    Grid::parse(&input, Topology::Bounded, |c| {
+        Seat::from_str(&c.to_string())
+    })
+    .map_err(|e| e.with_day(11))
+}
+
+#[aoc(day11, part1)]
+fn part1(input: &Grid<Seat>) -> Result<usize, SolveError> {
+    settle(input, CROWDED1.get()?, |grid, point| {
+        grid.neighbours8(point)
+            .filter(|(_, &seat)| seat == Seat::Occupied)
+            .count()
+    })
+}
+
+#[aoc(day11, part2)]
+fn part2(input: &Grid<Seat>) -> Result<usize, SolveError> {
+    settle(input, CROWDED2.get()?, |grid, point| {
+        grid.visible(point, |&seat| seat != Seat::Floor)
+            .filter(|(_, &seat)| seat == Seat::Occupied)
+            .count()
+    })
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const PARAMS: &'static [&'static str] = &[CROWDED1.name, CROWDED2.name];
+
+    type Input = Grid<Seat>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Output1, SolveError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Output2, SolveError> {
+        part2(input)
+    }
+}
+
+aoc_test! {
+  example seats {
+    file = "example.txt";
+
+    part1 = "37";
+    part2 = "26";
+  }
+}
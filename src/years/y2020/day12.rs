@@ -0,0 +1,150 @@
+use aoc_runner_derive::aoc;
+use aoc_runner_derive::aoc_generator;
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+use crate::aoc_test;
+use crate::error::{check_lines, parse_lines, ParseError, SolveError};
+use crate::input::normalize;
+use crate::solution::Solution;
+use crate::vec2::{Heading, Turn, Vec2};
+
+pub enum Action {
+    Move(Heading, i64),
+    Turn(Turn, i64),
+    Forward(i64),
+}
+
+impl FromStr for Action {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        let act = chars
+            .next()
+            .ok_or_else(|| ParseError::new("Unable to get first char", s))?;
+        let raw_arg = chars.as_str();
+        let arg = raw_arg
+            .parse::<i64>()
+            .map_err(|_| ParseError::new("Unable to parse arg", raw_arg).with_column(2))?;
+
+        // Turns only ever come in quarter turns
+        let quarters = || match arg % 90 {
+            0 => Ok(arg / 90),
+            _ => Err(ParseError::new("Invalid angle", raw_arg).with_column(2)),
+        };
+
+        match act {
+            'L' => Ok(Action::Turn(Turn::Left, quarters()?)),
+            'R' => Ok(Action::Turn(Turn::Right, quarters()?)),
+            'F' => Ok(Action::Forward(arg)),
+            _ => match Heading::try_from(act) {
+                Ok(heading) => Ok(Action::Move(heading, arg)),
+                Err(_) => Err(ParseError::new("Invalid action", act.to_string()).with_column(1)),
+            },
+        }
+    }
+}
+
+#[aoc_generator(day12)]
+fn generator(input: &str) -> Result<Vec<Action>, ParseError> {
+    let input = normalize(input);
+
+    parse_lines(12, &input, Action::from_str)
+}
+
+#[aoc(day12, part1)]
+fn part1(input: &[Action]) -> Result<i64, SolveError> {
+    let mut ship = Vec2::ZERO;
+    let mut heading = Heading::East;
+
+    for action in input {
+        match *action {
+            Action::Move(towards, step) => ship = travel(ship, towards.step(), step)?,
+            Action::Turn(turn, quarters) => heading = heading.turn(turn, quarters),
+            Action::Forward(step) => ship = travel(ship, heading.step(), step)?,
+        }
+    }
+
+    distance(ship)
+}
+
+#[aoc(day12, part2)]
+fn part2(input: &[Action]) -> Result<i64, SolveError> {
+    let mut ship = Vec2::ZERO;
+    // 10 east and 1 north of the ship
+    let mut waypoint = Vec2::new(10, -1);
+
+    for action in input {
+        match *action {
+            Action::Move(towards, step) => waypoint = travel(waypoint, towards.step(), step)?,
+            Action::Turn(turn, quarters) => {
+                waypoint = waypoint
+                    .checked_turn(turn, quarters)
+                    .ok_or_else(|| too_far(waypoint))?
+            }
+            Action::Forward(step) => ship = travel(ship, waypoint, step)?,
+        }
+    }
+
+    distance(ship)
+}
+
+/// `from` moved `times` lots of `step`
+fn travel(from: Vec2, step: Vec2, times: i64) -> Result<Vec2, SolveError> {
+    step.checked_scale(times)
+        .and_then(|step| from.checked_add(step))
+        .ok_or_else(|| too_far(from))
+}
+
+fn distance(ship: Vec2) -> Result<i64, SolveError> {
+    ship.manhattan(Vec2::ZERO).ok_or_else(|| {
+        SolveError::NoAnswer(format!("The distance to {} is too big for an i64", ship))
+    })
+}
+
+fn too_far(from: Vec2) -> SolveError {
+    SolveError::NoAnswer(format!(
+        "Moving on from {} leaves the range of an i64",
+        from
+    ))
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Vec<Action>;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        generator(input)
+    }
+
+    fn check(input: &str) -> Vec<ParseError> {
+        check_lines(12, &normalize(input), Action::from_str)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Output1, SolveError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Output2, SolveError> {
+        part2(input)
+    }
+}
+
+aoc_test! {
+  input = "
+    F10
+    N3
+    F7
+    R90
+    F11
+  ";
+
+  part1 = "25";
+  part2 = "286";
+}
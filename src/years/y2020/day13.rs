@@ -0,0 +1,268 @@
+use aoc_runner_derive::aoc;
+use aoc_runner_derive::aoc_generator;
+use num_bigint::BigInt;
+use std::str::FromStr;
+
+use crate::aoc_test;
+use crate::budget::Budget;
+use crate::error::{ParseError, SolveError};
+use crate::input::normalize;
+use crate::math::big;
+use crate::solution::Solution;
+use crate::trace;
+
+pub enum Line {
+    Bus(u64),
+    None,
+}
+
+impl FromStr for Line {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "x" {
+            return Ok(Line::None);
+        }
+
+        let n = s
+            .parse::<u64>()
+            .map_err(|_| ParseError::new("Unable to parse number", s))?;
+        Ok(Line::Bus(n))
+    }
+}
+
+#[aoc_generator(day13)]
+fn generator(input: &str) -> Result<(u64, Vec<Line>), ParseError> {
+    let input = normalize(input);
+
+    let mut input = input.lines();
+
+    let time = input.next().ok_or_else(|| {
+        ParseError::new("Unable to get first line", "")
+            .with_day(13)
+            .with_line(1)
+    })?;
+    let time = time.parse::<u64>().map_err(|_| {
+        ParseError::new("Unable to parse number", time)
+            .with_day(13)
+            .with_line(1)
+    })?;
+
+    let busses = input.next().ok_or_else(|| {
+        ParseError::new("Unable to get second line", "")
+            .with_day(13)
+            .with_line(2)
+    })?;
+
+    // Keep track of where each id starts so bad ids can be pointed out
+    let mut column = 1;
+    let mut lines = Vec::new();
+    for id in busses.split(',') {
+        let line =
+            Line::from_str(id).map_err(|e| e.with_day(13).with_line(2).with_column(column))?;
+
+        lines.push(line);
+        column += id.len() + 1;
+    }
+
+    Ok((time, lines))
+}
+
+#[aoc(day13, part1)]
+fn part1(input: &(u64, Vec<Line>)) -> Result<u64, SolveError> {
+    let start_time = input.0;
+    let busses = &input
+        .1
+        .iter()
+        // Remove all instances of "x"
+        .filter_map(|id| match id {
+            Line::Bus(bus_id) => Some(*bus_id),
+            _ => None,
+        })
+        .collect::<Vec<u64>>();
+
+    let largest = busses
+        .iter()
+        .max()
+        .ok_or_else(|| SolveError::NoAnswer("There are no buses".to_owned()))?;
+
+    // Every bus leaves within its own id of any time, so the largest id bounds the wait
+    let mut budget = Budget::new(largest.saturating_add(1));
+
+    let mut time = start_time;
+    loop {
+        for bus in busses {
+            if time.is_multiple_of(*bus) {
+                return (time - start_time).checked_mul(*bus).ok_or_else(|| {
+                    SolveError::NoAnswer(format!(
+                        "Waiting {} minutes for bus {} is too big for a u64",
+                        time - start_time,
+                        bus
+                    ))
+                });
+            }
+        }
+
+        budget.tick()?;
+        time = time
+            .checked_add(1)
+            .ok_or_else(|| SolveError::NoAnswer(format!("No bus leaves by {}", time)))?;
+    }
+}
+
+#[aoc(day13, part2)]
+fn part2(input: &(u64, Vec<Line>)) -> Result<BigInt, SolveError> {
+    let mut system = big::congruence(BigInt::from(0), BigInt::from(1))?;
+
+    for (i, line) in input.1.iter().enumerate() {
+        if let Line::Bus(id) = line {
+            // Bus id leaves i minutes after t, so t ≡ -i (mod id)
+            let bus = big::congruence(-BigInt::from(i), BigInt::from(*id))?;
+            system = big::merge(&system, &bus)?;
+
+            trace!(
+                13,
+                "bus {} at offset {}: t = {}, stepping by {}",
+                id,
+                i,
+                system.residue,
+                system.modulus
+            );
+        }
+    }
+
+    Ok(system.residue)
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = (u64, Vec<Line>);
+    type Output1 = u64;
+    type Output2 = BigInt;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Output1, SolveError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Output2, SolveError> {
+        part2(input)
+    }
+}
+
+aoc_test! {
+  example schedule {
+    input = "
+      939
+      7,13,x,x,59,x,31,19
+    ";
+
+    part1 = "295";
+    part2 = "1068781";
+  }
+
+  example short {
+    input = "
+      0
+      17,x,13,19
+    ";
+
+    part2 = "3417";
+  }
+
+  example no_gaps {
+    input = "
+      0
+      67,7,59,61
+    ";
+
+    part2 = "754018";
+  }
+
+  example large {
+    input = "
+      0
+      1789,37,47,1889
+    ";
+
+    part2 = "1202161486";
+  }
+}
+
+// Cross-checks the Chinese remainder solution against trying every timestamp in turn
+#[cfg(test)]
+mod properties {
+    use super::*;
+    use proptest::prelude::*;
+
+    const PRIMES: [u64; 9] = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+
+    fn reference_part1(time: u64, busses: &[Option<u64>]) -> u64 {
+        busses
+            .iter()
+            .flatten()
+            .map(|&bus| (bus, (bus - time % bus) % bus))
+            .min_by_key(|&(_, wait)| wait)
+            .map(|(bus, wait)| bus * wait)
+            .expect("At least one bus")
+    }
+
+    fn reference_part2(busses: &[Option<u64>]) -> u64 {
+        let aligned = |time: u64| {
+            busses.iter().enumerate().all(|(i, bus)| match bus {
+                Some(bus) => (time + i as u64).is_multiple_of(*bus),
+                None => true,
+            })
+        };
+
+        (0..).find(|&time| aligned(time)).expect("Ids are coprime")
+    }
+
+    /// Schedules of distinct small primes, so every schedule lines up eventually, with gaps between them
+    fn schedule() -> impl Strategy<Value = Vec<Option<u64>>> {
+        prop::sample::subsequence(PRIMES.to_vec(), 1..5)
+            .prop_shuffle()
+            .prop_flat_map(|ids| {
+                let gaps = prop::collection::vec(0usize..3, ids.len());
+
+                (Just(ids), gaps)
+            })
+            .prop_map(|(ids, gaps)| {
+                ids.iter()
+                    .zip(gaps)
+                    .flat_map(|(&id, gap)| std::iter::repeat_n(None, gap).chain(Some(Some(id))))
+                    .collect()
+            })
+    }
+
+    fn render(time: u64, busses: &[Option<u64>]) -> String {
+        let busses: Vec<String> = busses
+            .iter()
+            .map(|bus| bus.map_or("x".to_owned(), |bus| bus.to_string()))
+            .collect();
+
+        format!("{}\n{}", time, busses.join(","))
+    }
+
+    proptest! {
+        #[test]
+        fn part1_matches_reference(time in 0u64..10_000, busses in schedule()) {
+            let parsed = generator(&render(time, &busses)).unwrap();
+
+            prop_assert_eq!(part1(&parsed), Ok(reference_part1(time, &busses)));
+        }
+
+        #[test]
+        fn part2_matches_reference(busses in schedule()) {
+            let parsed = generator(&render(0, &busses)).unwrap();
+
+            prop_assert_eq!(part2(&parsed), Ok(BigInt::from(reference_part2(&busses))));
+        }
+    }
+}
@@ -0,0 +1,370 @@
+use aoc_runner_derive::aoc;
+use aoc_runner_derive::aoc_generator;
+use regex::Regex;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use crate::aoc_test;
+use crate::error::{check_lines, parse_lines, ParseError, SolveError};
+use crate::input::normalize;
+use crate::solution::Solution;
+
+/// Memory addresses and values are both 36-bit
+const MASK_BITS: usize = 36;
+
+/// Every floating bit doubles the addresses a write goes to. Real inputs never have more than nine, but part 1's example
+/// has 34, which version 2 of the decoder would never finish writing.
+const MAX_FLOATING: u32 = 9;
+
+#[derive(Clone)]
+pub struct Bitmask {
+    include: u64,
+    exclude: u64,
+    floating: u64,
+}
+
+impl Bitmask {
+    fn new(include: u64, exclude: u64, floating: u64) -> Bitmask {
+        Bitmask {
+            include,
+            exclude,
+            floating,
+        }
+    }
+
+    const fn empty() -> Bitmask {
+        Bitmask {
+            include: 0,
+            exclude: 1,
+            floating: 0,
+        }
+    }
+
+    fn apply(&self, other: u64) -> u64 {
+        let mut result = other;
+
+        result |= self.include;
+        result &= self.exclude;
+
+        result
+    }
+
+    fn apply_v2(&self, other: usize) -> Result<Vec<usize>, SolveError> {
+        let floating_bits = self.floating.count_ones();
+        if floating_bits > MAX_FLOATING {
+            return Err(SolveError::NoAnswer(format!(
+                "Mask has {} floating bits, more than the {} a mask can have",
+                floating_bits, MAX_FLOATING
+            )));
+        }
+
+        let other = (other as u64 | self.include) & !self.floating;
+
+        // Count down through every subset of the floating bits, each giving one combination of their values
+        let mut addresses = Vec::with_capacity(1 << floating_bits);
+        let mut subset = self.floating;
+        loop {
+            addresses.push((other | subset) as usize);
+
+            if subset == 0 {
+                return Ok(addresses);
+            }
+            subset = (subset - 1) & self.floating;
+        }
+    }
+}
+
+impl FromStr for Bitmask {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some((i, c)) = s.char_indices().find(|(_, c)| !"01X".contains(*c)) {
+            return Err(ParseError::new("Invalid mask bit", c.to_string()).with_column(i + 1));
+        }
+
+        // Point at the first bit too many, or just past the end of a mask that's too short
+        if s.len() != MASK_BITS {
+            return Err(
+                ParseError::new(format!("Mask must be {} bits long", MASK_BITS), s)
+                    .with_column(s.len().min(MASK_BITS) + 1),
+            );
+        }
+
+        let include = s.replace("X", "0");
+        let exclude = s.replace("X", "1");
+        let floating = s.replace("1", "0").replace("X", "1");
+
+        let parse = |bits: &str| {
+            u64::from_str_radix(bits, 2).map_err(|_| ParseError::new("Unable to parse u64", s))
+        };
+
+        Ok(Bitmask::new(
+            parse(&include)?,
+            parse(&exclude)?,
+            parse(&floating)?,
+        ))
+    }
+}
+
+#[derive(Clone)]
+pub enum Operation {
+    Mask(Bitmask),
+    Mem(usize, u64),
+}
+
+impl FromStr for Operation {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref MASK_RE: Regex =
+                Regex::new(r"^mask = (\w+)$").expect("Failed to initialize regex");
+            static ref MEM_RE: Regex =
+                Regex::new(r"^mem\[(\d+)\] = (\d+)$").expect("Failed to initialize regex");
+        }
+
+        if let Some(caps) = MASK_RE.captures(s) {
+            let mask = caps.get(1).expect("Regex has a capture group");
+
+            return Bitmask::from_str(mask.as_str())
+                .map(Operation::Mask)
+                .map_err(|e| {
+                    // Bitmask columns are relative to the start of the mask
+                    let column = mask.start() + e.column.max(1);
+                    e.with_column(column)
+                });
+        }
+
+        let caps = MEM_RE
+            .captures(s)
+            .ok_or_else(|| ParseError::new("Unable to parse input", s).with_column(1))?;
+
+        let index = caps.get(1).expect("Regex has a capture group");
+        let index = index.as_str().parse::<usize>().map_err(|_| {
+            ParseError::new("Unable to convert to usize", index.as_str())
+                .with_column(index.start() + 1)
+        })?;
+
+        let value = caps.get(2).expect("Regex has a capture group");
+        let value = value.as_str().parse::<u64>().map_err(|_| {
+            ParseError::new("Unable to convert to u64", value.as_str())
+                .with_column(value.start() + 1)
+        })?;
+
+        Ok(Operation::Mem(index, value))
+    }
+}
+
+#[aoc_generator(day14)]
+fn generator(input: &str) -> Result<Vec<Operation>, ParseError> {
+    let input = normalize(input);
+
+    parse_lines(14, &input, Operation::from_str)
+}
+
+const DEFAULT_MASK: Bitmask = Bitmask::empty();
+
+#[aoc(day14, part1)]
+fn part1(input: &[Operation]) -> u64 {
+    let mut mem = HashMap::new();
+    let mut mask = DEFAULT_MASK;
+
+    input.iter().for_each(|op| match op {
+        Operation::Mask(new_mask) => {
+            mask = new_mask.clone();
+        }
+        Operation::Mem(i, val) => {
+            let val = mask.apply(*val);
+
+            mem.insert(i, val);
+        }
+    });
+
+    mem.values().sum()
+}
+
+#[aoc(day14, part2)]
+fn part2(input: &[Operation]) -> Result<u64, SolveError> {
+    let mut mem = HashMap::new();
+    let mut mask = DEFAULT_MASK;
+
+    for op in input {
+        match op {
+            Operation::Mask(new_mask) => {
+                mask = new_mask.clone();
+            }
+            Operation::Mem(i, val) => {
+                let indicies = mask.apply_v2(*i)?;
+
+                indicies.iter().for_each(|i| {
+                    mem.insert(*i, *val);
+                });
+            }
+        }
+    }
+
+    Ok(mem.values().sum())
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input = Vec<Operation>;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        generator(input)
+    }
+
+    fn check(input: &str) -> Vec<ParseError> {
+        check_lines(14, &normalize(input), Operation::from_str)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Output1, SolveError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Output2, SolveError> {
+        part2(input)
+    }
+}
+
+aoc_test! {
+  example values {
+    input = "
+      mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X
+      mem[8] = 11
+      mem[7] = 101
+      mem[8] = 0
+    ";
+
+    part1 = "165";
+  }
+
+  example addresses {
+    input = "
+      mask = 000000000000000000000000000000X1001X
+      mem[42] = 100
+      mask = 00000000000000000000000000000000X0XX
+      mem[26] = 1
+    ";
+
+    part2 = "208";
+  }
+}
+
+// Cross-checks the precomputed masks against applying each mask one character at a time
+#[cfg(test)]
+mod properties {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Every address `mask` writes to, expanding each X into both bit values
+    fn reference_addresses(mask: &str, address: u64) -> Vec<u64> {
+        let mut addresses = vec![0];
+
+        for (i, c) in mask.chars().enumerate() {
+            let bit = 35 - i;
+
+            addresses = match c {
+                '0' => addresses
+                    .iter()
+                    .map(|a| a | (address & (1 << bit)))
+                    .collect(),
+                '1' => addresses.iter().map(|a| a | (1 << bit)).collect(),
+                _ => addresses
+                    .iter()
+                    .flat_map(|a| vec![*a, a | (1 << bit)])
+                    .collect(),
+            };
+        }
+
+        addresses
+    }
+
+    fn reference_value(mask: &str, value: u64) -> u64 {
+        mask.chars().enumerate().fold(value, |value, (i, c)| {
+            let bit = 35 - i;
+
+            match c {
+                '0' => value & !(1 << bit),
+                '1' => value | (1 << bit),
+                _ => value,
+            }
+        })
+    }
+
+    type Program = Vec<(String, Vec<(u64, u64)>)>;
+
+    fn run_reference(program: &Program, part2: bool) -> u64 {
+        let mut mem = HashMap::new();
+
+        for (mask, writes) in program {
+            for &(address, value) in writes {
+                if part2 {
+                    for address in reference_addresses(mask, address) {
+                        mem.insert(address, value);
+                    }
+                } else {
+                    mem.insert(address, reference_value(mask, value));
+                }
+            }
+        }
+
+        mem.values().sum()
+    }
+
+    /// Masks with a handful of floating bits in the low byte, so addresses written by different masks overlap
+    fn mask() -> impl Strategy<Value = String> {
+        let fixed = prop::collection::vec(prop_oneof![Just('0'), Just('1')], 28);
+        let low = prop::collection::vec(
+            prop_oneof![3 => Just('0'), 3 => Just('1'), 2 => Just('X')],
+            8,
+        )
+        .prop_filter("Too many floating bits", |bits| {
+            bits.iter().filter(|&&bit| bit == 'X').count() <= 4
+        });
+
+        (fixed, low).prop_map(|(fixed, low)| fixed.into_iter().chain(low).collect())
+    }
+
+    fn program() -> impl Strategy<Value = Program> {
+        let writes = prop::collection::vec((0u64..256, 0u64..1 << 36), 1..5);
+
+        prop::collection::vec((mask(), writes), 1..5)
+    }
+
+    fn render(program: &Program) -> String {
+        let mut lines = Vec::new();
+
+        for (mask, writes) in program {
+            lines.push(format!("mask = {}", mask));
+            lines.extend(
+                writes
+                    .iter()
+                    .map(|(address, value)| format!("mem[{}] = {}", address, value)),
+            );
+        }
+
+        lines.join("\n")
+    }
+
+    proptest! {
+        #[test]
+        fn part1_matches_reference(program in program()) {
+            let parsed = generator(&render(&program)).unwrap();
+
+            prop_assert_eq!(part1(&parsed), run_reference(&program, false));
+        }
+
+        #[test]
+        fn part2_matches_reference(program in program()) {
+            let parsed = generator(&render(&program)).unwrap();
+
+            prop_assert_eq!(part2(&parsed), Ok(run_reference(&program, true)));
+        }
+    }
+}
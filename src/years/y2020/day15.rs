@@ -0,0 +1,190 @@
+use aoc_runner_derive::aoc;
+use aoc_runner_derive::aoc_generator;
+
+use std::collections::HashMap;
+
+use crate::aoc_test;
+use crate::budget::Budget;
+use crate::error::{ParseError, SolveError};
+use crate::input::normalize;
+use crate::params::Param;
+use crate::solution::Solution;
+use crate::trace;
+
+/// Which turn's number each part asks for
+const TURNS1: Param<usize> = Param::new(15, "part1_turns", || 2020);
+const TURNS2: Param<usize> = Param::new(15, "part2_turns", || 30_000_000);
+
+#[aoc_generator(day15)]
+fn generator(input: &str) -> Result<Vec<u32>, ParseError> {
+    let input = normalize(input);
+
+    let mut column = 1;
+    let mut numbers = Vec::new();
+    for s in input.split(',') {
+        let n = s.parse::<u32>().map_err(|_| {
+            ParseError::new("Unable to parse u32", s)
+                .with_day(15)
+                .with_line(1)
+                .with_column(column)
+        })?;
+
+        numbers.push(n);
+        column += s.len() + 1;
+    }
+
+    Ok(numbers)
+}
+
+/// A part's turn count, which has to cover the starting numbers for the game to be played at all
+fn turns(param: &Param<usize>, input: &[u32]) -> Result<usize, SolveError> {
+    let turns = param.get()?;
+
+    if turns < input.len() {
+        return Err(SolveError::InvalidParameter {
+            name: param.name.to_owned(),
+            reason: format!("The game starts with {} turns", input.len()),
+        });
+    }
+
+    Ok(turns)
+}
+
+#[aoc(day15, part1)]
+fn part1(input: &[u32]) -> Result<u32, SolveError> {
+    let turns = turns(&TURNS1, input)?;
+    let len = input.len();
+
+    // Convert input into a vec of tuples like (val, i)
+    let mut input = input
+        .iter()
+        .enumerate()
+        .map(|(i, val)| (*val, i))
+        .collect::<Vec<(u32, usize)>>();
+    let mut used = HashMap::new();
+
+    let last = input.remove(input.len() - 1).0;
+
+    // Add the initial state to the map. The keys are the numbers and the values are the index they last appeared
+    used.extend(input);
+
+    // The game always ends, but a big enough turn count can take longer than anyone wants to wait
+    let mut budget = Budget::new(turns as u64);
+
+    let mut prev = last;
+    for n in len - 1..turns - 1 {
+        budget.tick()?;
+        let search = used.get(&prev);
+
+        let curr = match search {
+            Some(i) => (n - i) as u32,
+            None => 0,
+        };
+        trace!(
+            15,
+            "turn {}: {} was last spoken at turn {:?}, so say {}",
+            n + 2,
+            prev,
+            search.map(|i| i + 1),
+            curr
+        );
+
+        used.insert(prev, n);
+        prev = curr;
+    }
+
+    Ok(prev)
+}
+
+#[aoc(day15, part2)]
+fn part2(input: &[u32]) -> Result<u32, SolveError> {
+    let turns = turns(&TURNS2, input)?;
+    let len = input.len();
+
+    // Convert input into a vec of tuples like (val, i)
+    let mut input = input
+        .iter()
+        .enumerate()
+        .map(|(i, val)| (*val, i))
+        .collect::<Vec<(u32, usize)>>();
+    let mut used = HashMap::new();
+
+    let last = input.remove(input.len() - 1).0;
+
+    // Add the initial state to the map. The keys are the numbers and the values are the index they last appeared
+    used.extend(input);
+
+    // The game always ends, but a big enough turn count can take longer than anyone wants to wait
+    let mut budget = Budget::new(turns as u64);
+
+    let mut prev = last;
+    for n in len - 1..turns - 1 {
+        budget.tick()?;
+        let search = used.get(&prev);
+
+        let curr = match search {
+            Some(i) => (n - i) as u32,
+            None => 0,
+        };
+
+        used.insert(prev, n);
+        prev = curr;
+    }
+
+    Ok(prev)
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    const PARAMS: &'static [&'static str] = &[TURNS1.name, TURNS2.name];
+
+    type Input = Vec<u32>;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Output1, SolveError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Output2, SolveError> {
+        part2(input)
+    }
+}
+
+aoc_test! {
+  example trailing_newline {
+    file = "trailing_newline.txt";
+
+    part1 = "436";
+  }
+
+  example first {
+    input = "0,3,6";
+
+    part1 = "436";
+  }
+
+  example second {
+    input = "1,3,2";
+
+    part1 = "1";
+  }
+
+  example third {
+    input = "2,1,3";
+
+    part1 = "10";
+  }
+
+  example fourth {
+    input = "3,1,2";
+
+    part1 = "1836";
+  }
+}
@@ -0,0 +1,99 @@
+use crate::solution::Entry;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+
+/// Every implemented day of 2020, in order
+pub static ALL: &[Entry] = &[
+    Entry::new::<day01::Day01>(),
+    Entry::new::<day02::Day02>(),
+    Entry::new::<day03::Day03>(),
+    Entry::new::<day04::Day04>(),
+    Entry::new::<day05::Day05>(),
+    Entry::new::<day06::Day06>(),
+    Entry::new::<day07::Day07>(),
+    Entry::new::<day08::Day08>(),
+    Entry::new::<day09::Day09>(),
+    Entry::new::<day10::Day10>(),
+    Entry::new::<day11::Day11>(),
+    Entry::new::<day12::Day12>(),
+    Entry::new::<day13::Day13>(),
+    Entry::new::<day14::Day14>(),
+    Entry::new::<day15::Day15>(),
+];
+
+/// The input of every `aoc_test!` example, along with its day and name
+#[cfg(test)]
+pub(crate) fn examples() -> Vec<(u8, &'static str, String)> {
+    let examples = vec![
+        (1, day01::tests::examples()),
+        (2, day02::tests::examples()),
+        (3, day03::tests::examples()),
+        (4, day04::tests::examples()),
+        (5, day05::tests::examples()),
+        (6, day06::tests::examples()),
+        (7, day07::tests::examples()),
+        (8, day08::tests::examples()),
+        (9, day09::tests::examples()),
+        (10, day10::tests::examples()),
+        (11, day11::tests::examples()),
+        (12, day12::tests::examples()),
+        (13, day13::tests::examples()),
+        (14, day14::tests::examples()),
+        (15, day15::tests::examples()),
+    ];
+
+    examples
+        .into_iter()
+        .flat_map(|(day, examples)| {
+            examples
+                .into_iter()
+                .map(move |(name, input)| (day, name, input))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answer::Answer;
+    use crate::solution::Part;
+    use crate::years;
+
+    #[test]
+    fn dispatch() {
+        let year = years::get(2020).expect("2020 is registered");
+        let entry = year.get(1).expect("Day 1 is registered");
+
+        assert_eq!(
+            entry.run("1721\n979\n366\n299\n675\n1456", Part::One),
+            Ok(Answer::Int(514579))
+        );
+        assert!(year.get(26).is_none());
+        assert!(years::get(1999).is_none());
+    }
+
+    #[test]
+    fn check() {
+        let entry = years::get(2020)
+            .and_then(|year| year.get(8))
+            .expect("Day 8 is registered");
+        let errors = entry.check("nop +0\nfoo +1\nacc +1\njmp one");
+
+        let lines: Vec<usize> = errors.iter().map(|e| e.line).collect();
+        assert_eq!(lines, vec![2, 4]);
+        assert!(entry.check("nop +0\nacc +1").is_empty());
+    }
+}
@@ -0,0 +1,60 @@
+use aoc_runner_derive::aoc;
+use aoc_runner_derive::aoc_generator;
+
+use crate::aoc_test;
+use crate::error::{check_lines, parse_lines, ParseError, SolveError};
+use crate::input::normalize;
+use crate::solution::Solution;
+
+#[aoc_generator(day{{day}})]
+fn generator(input: &str) -> Result<Vec<String>, ParseError> {
+    let input = normalize(input);
+
+    parse_lines({{day}}, &input, parse_line)
+}
+
+fn parse_line(line: &str) -> Result<String, ParseError> {
+    Ok(line.to_owned())
+}
+
+#[aoc(day{{day}}, part1)]
+fn part1(_input: &[String]) -> Result<u64, SolveError> {
+    Err(SolveError::NoAnswer("Part 1 isn't solved yet".to_owned()))
+}
+
+#[aoc(day{{day}}, part2)]
+fn part2(_input: &[String]) -> Result<u64, SolveError> {
+    Err(SolveError::NoAnswer("Part 2 isn't solved yet".to_owned()))
+}
+
+pub struct Day{{padded}};
+
+impl Solution for Day{{padded}} {
+    const DAY: u8 = {{day}};
+
+    type Input = Vec<String>;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        generator(input)
+    }
+
+    fn check(input: &str) -> Vec<ParseError> {
+        check_lines({{day}}, &normalize(input), parse_line)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Output1, SolveError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Output2, SolveError> {
+        part2(input)
+    }
+}
+
+// Paste the puzzle's example here, then add `part1 = "...";` and `part2 = "...";` once the answers are known
+aoc_test! {
+  input = "
+  ";
+}